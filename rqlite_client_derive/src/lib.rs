@@ -0,0 +1,161 @@
+/*!
+
+Derive macros of [`rqlite_client`](https://docs.rs/rqlite_client/)
+
+Use them with feature `derive` of `rqlite_client`, which re-exports the macros.
+
+*/
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{ext::IdentExt, parse_macro_input, Data, DeriveInput, Field, Fields, LitStr, Type};
+
+/// Derive `rqlite_client::FromRow` for a struct with named fields
+///
+/// Every field is decoded with `rqlite_client::FromSqlValue` from the column with the name of the field.
+///
+/// The field attribute `#[rqlite(...)]` supports
+///
+/// * `rename = "column"` to decode from another column name
+/// * `default` to use `Default::default()`, if the column is missing or `NULL`
+/// * `default = "path"` to call the function `path`, if the column is missing or `NULL`
+/// * `skip` to always use `Default::default()`
+///
+/// Fields of type `Option<T>` are `None`, if the column is missing or `NULL`.
+///
+#[proc_macro_derive(FromRow, attributes(rqlite))]
+pub fn derive_from_row(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    from_row(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Default of a field for a missing column or `NULL`
+enum FieldDefault {
+    Default,
+    None,
+    Path(syn::ExprPath),
+}
+
+/// Parsed `#[rqlite(...)]` attributes of a field
+struct FieldAttrs {
+    default: FieldDefault,
+    is_skip: bool,
+    rename: Option<String>,
+}
+
+impl FieldAttrs {
+    fn parse(field: &Field) -> syn::Result<Self> {
+        let mut attrs = Self {
+            default: FieldDefault::None,
+            is_skip: false,
+            rename: None,
+        };
+
+        for attr in &field.attrs {
+            if !attr.path().is_ident("rqlite") {
+                continue;
+            }
+
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("default") {
+                    attrs.default = if meta.input.peek(syn::Token![=]) {
+                        FieldDefault::Path(meta.value()?.parse::<LitStr>()?.parse()?)
+                    } else {
+                        FieldDefault::Default
+                    };
+                    Ok(())
+                } else if meta.path.is_ident("rename") {
+                    attrs.rename = Some(meta.value()?.parse::<LitStr>()?.value());
+                    Ok(())
+                } else if meta.path.is_ident("skip") {
+                    attrs.is_skip = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported rqlite attribute"))
+                }
+            })?;
+        }
+
+        Ok(attrs)
+    }
+}
+
+/// Generate the impl of `FromRow`
+fn from_row(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            input,
+            "FromRow supports only structs",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(syn::Error::new_spanned(
+            &data.fields,
+            "FromRow supports only structs with named fields",
+        ));
+    };
+
+    let mut inits = Vec::with_capacity(fields.named.len());
+
+    for field in &fields.named {
+        let Some(ident) = &field.ident else {
+            continue;
+        };
+        let attrs = FieldAttrs::parse(field)?;
+        let column = attrs.rename.unwrap_or_else(|| ident.unraw().to_string());
+
+        let init = if attrs.is_skip {
+            quote!(::core::default::Default::default())
+        } else {
+            match attrs.default {
+                FieldDefault::Default => {
+                    quote!(row.get_opt(#column)?.unwrap_or_default())
+                }
+                FieldDefault::Path(path) => {
+                    quote!(row.get_opt(#column)?.unwrap_or_else(#path))
+                }
+                FieldDefault::None if is_option(&field.ty) => {
+                    quote!(row.get_opt(#column)?)
+                }
+                FieldDefault::None => quote!(row.try_get(#column)?),
+            }
+        };
+
+        inits.push(quote!(#ident: #init));
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::rqlite_client::FromRow for #name #ty_generics #where_clause {
+            fn from_row(
+                row: &::rqlite_client::response::mapping::Row<'_>,
+            ) -> ::core::result::Result<Self, ::rqlite_client::Error> {
+                ::core::result::Result::Ok(Self {
+                    #(#inits),*
+                })
+            }
+        }
+    })
+}
+
+/// Check for type `Option<T>`
+fn is_option(ty: &Type) -> bool {
+    match ty {
+        Type::Path(type_path) => {
+            type_path.qself.is_none()
+                && type_path
+                    .path
+                    .segments
+                    .last()
+                    .is_some_and(|segment| segment.ident == "Option")
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,35 @@
+//! Trait [`AsyncRequestBuilder`] is implemented to build a _runnable_ asynchronous HTTP request
+
+use std::{future::Future, ops::Deref, pin::Pin};
+
+use crate::{
+    query::{Query, State},
+    Connection,
+};
+
+/// Boxed [`Future`] returned by [`AsyncRequestBuilder::run_async()`]
+pub type ResponseFuture = Pin<Box<dyn Future<Output = crate::response::Result> + Send>>;
+
+/// Trait [`AsyncRequestBuilder`] is implemented to build a _runnable_ asynchronous HTTP request
+///
+/// It is the asynchronous counterpart of [`RequestBuilder`](crate::RequestBuilder).
+///
+/// The returned [`ResponseFuture`] is `'static` and can't borrow the [`Query`].
+/// Take everything needed for the request before creating the `Future`, e.g. the url
+/// with [`Query::url()`] and the statements with [`Query::sql()`].
+///
+/// The `Request` is sent to the node of the `Query` only. There is no failover to other nodes,
+/// no retry with a [`RetryPolicy`](crate::RetryPolicy) and no leader pinning like with
+/// [`Query::request_run()`].
+///
+pub trait AsyncRequestBuilder<T: State> {
+    /// Run the __request__ asynchronously
+    ///
+    /// # Errors
+    ///
+    /// The `Future` may resolve to [`crate::error::Error`]
+    ///
+    fn run_async<C>(&self, query: &Query<'_, T, C>) -> ResponseFuture
+    where
+        C: Deref<Target = Connection>;
+}
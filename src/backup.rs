@@ -0,0 +1,196 @@
+//! `Backup` state for `Query<State>`
+
+use std::ops::Deref;
+
+use crate::{state::State, Connection, Query};
+
+/// _Backup_ retrieves a consistent copy of the database from the leader of the cluster
+///
+/// The backup is written to any [`std::io::Write`] with
+/// [`Query::request_backup()`](crate::Query::request_backup), so that large databases
+/// are not held in memory.
+/// [`Query::request_run()`](crate::Query::request_run) fails for a _Backup_.
+///
+/// See <https://rqlite.io/docs/guides/backup/>
+///
+#[derive(Debug, Eq, PartialEq)]
+pub struct Backup;
+impl State for Backup {}
+
+/// [`Format`] of the [`Backup`] or of the data to [`Load`](crate::load::Load)
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Format {
+    /// SQL text dump of the database
+    Sql,
+    /// Binary _SQLite_ database file
+    #[default]
+    Sqlite,
+}
+
+impl std::fmt::Display for Format {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Sql => f.write_str("sql"),
+            Self::Sqlite => f.write_str("sqlite"),
+        }
+    }
+}
+
+impl<C> Query<'_, Backup, C>
+where
+    C: Deref<Target = Connection>,
+{
+    /// Enable compress query param to retrieve the backup compressed with _gzip_
+    ///
+    /// See <https://rqlite.io/docs/guides/backup/#generating-a-compressed-backup>
+    #[must_use]
+    pub fn enable_compress(self) -> Self {
+        self.enable_compress_helper()
+    }
+
+    /// Enable noleader query param to retrieve the backup from the receiving node
+    /// instead of the leader
+    ///
+    /// See <https://rqlite.io/docs/guides/backup/>
+    #[must_use]
+    pub fn enable_noleader(self) -> Self {
+        self.enable_noleader_helper()
+    }
+
+    /// Enable vacuum query param to retrieve a vacuumed copy of the _SQLite_ database file
+    ///
+    /// See <https://rqlite.io/docs/guides/backup/#generating-a-vacuumed-backup>
+    #[must_use]
+    pub fn enable_vacuum(self) -> Self {
+        self.enable_vacuum_helper()
+    }
+
+    /// Set [`Format`] of the backup, by default [`Format::Sqlite`]
+    #[must_use]
+    pub fn set_format(self, format: Format) -> Self {
+        self.set_format_helper(format)
+    }
+
+    /// Set the `tables` to dump in the [`Format::Sql`]
+    ///
+    /// By default all tables are dumped.
+    ///
+    /// See <https://rqlite.io/docs/guides/backup/#sql-text-format>
+    #[must_use]
+    pub fn set_tables(self, tables: &[&str]) -> Self {
+        self.set_tables_helper(tables.iter().map(ToString::to_string).collect())
+    }
+
+    /// Run `Request` for the backup and write the response body to `writer`
+    ///
+    /// The body is copied as it is received, so it is not held in memory and
+    /// isn't parsed into a [`Response`](crate::Response).
+    ///
+    /// If the [`Connection`](crate::Connection) has multiple nodes, the next node is tried
+    /// on connection errors or HTTP status 5xx. There is no retry with a
+    /// [`RetryPolicy`](crate::RetryPolicy).
+    ///
+    /// Returns the number of bytes written.
+    ///
+    /// ```no_run
+    /// use rqlite_client::{backup::Format, Connection};
+    ///
+    /// # #[cfg(feature = "url")]
+    /// let connection = Connection::new("http://localhost:4001").expect("url failed");
+    /// # #[cfg(not(feature = "url"))]
+    /// # let connection = Connection::new("http://localhost:4001");
+    ///
+    /// let mut file = std::fs::File::create("backup.sql").expect("create failed");
+    ///
+    /// # #[cfg(any(feature = "reqwest", feature = "ureq"))]
+    /// let written = connection
+    ///     .backup()
+    ///     .set_format(Format::Sql)
+    ///     .request_backup(&mut file)
+    ///     .expect("backup failed");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// [Error](crate::Error) on failing [Request](crate::request::Request) run
+    /// or failing write to `writer`
+    ///
+    #[cfg(any(feature = "reqwest", feature = "ureq"))]
+    pub fn request_backup<W>(&self, writer: &mut W) -> Result<u64, crate::Error>
+    where
+        W: std::io::Write + ?Sized,
+    {
+        let mut reader = self.request_read()?;
+
+        Ok(std::io::copy(&mut reader, writer)?)
+    }
+}
+
+#[cfg(test)]
+#[cfg(any(feature = "percent_encoding", feature = "url"))]
+mod tests {
+    use std::{sync::OnceLock, time::Duration};
+
+    use super::Format;
+    use crate::Connection;
+
+    const TEST_CONNECTION_URL: &str = "http://localhost:4001/";
+
+    static TEST_CONNECTION: OnceLock<Connection> = OnceLock::new();
+
+    fn test_connection() -> &'static Connection {
+        TEST_CONNECTION.get_or_init(|| {
+            #[cfg(feature = "url")]
+            let c = Connection::new(TEST_CONNECTION_URL).unwrap();
+            #[cfg(not(feature = "url"))]
+            let c = Connection::new(TEST_CONNECTION_URL);
+
+            c
+        })
+    }
+
+    #[test]
+    fn backup_test() {
+        let mut q = test_connection().backup();
+
+        assert_eq!(&q.create_path_with_query(), "/db/backup");
+
+        q = q.set_format(Format::Sqlite).enable_vacuum();
+
+        assert_eq!(&q.create_path_with_query(), "/db/backup?vacuum");
+
+        q = q.enable_compress().enable_noleader();
+
+        assert_eq!(
+            &q.create_path_with_query(),
+            "/db/backup?compress&vacuum&noleader"
+        );
+
+        q = q.set_timeout(Duration::from_secs(3).into());
+        assert_eq!(
+            &q.create_path_with_query(),
+            "/db/backup?compress&vacuum&noleader&timeout=3s"
+        );
+    }
+
+    #[test]
+    fn backup_sql_test() {
+        let q = test_connection()
+            .backup()
+            .set_format(Format::Sql)
+            .set_tables(&["foo", "bar"]);
+
+        assert_eq!(
+            &q.create_path_with_query(),
+            "/db/backup?fmt=sql&tables=foo,bar"
+        );
+    }
+
+    #[cfg(any(feature = "reqwest", feature = "ureq"))]
+    #[test]
+    fn request_run_test() {
+        let err = test_connection().backup().request_run().unwrap_err();
+
+        assert!(err.to_string().contains("request_backup()"));
+    }
+}
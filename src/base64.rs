@@ -0,0 +1,89 @@
+//! `base64` standard encoding used for HTTP basic authentication and blobs
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Standard base64 encoding with padding
+pub(crate) fn encode(input: &[u8]) -> String {
+    let mut output = String::with_capacity((input.len() + 2) / 3 * 4);
+
+    for chunk in input.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let n = (u32::from(b[0]) << 16) | (u32::from(b[1]) << 8) | u32::from(b[2]);
+
+        for i in 0..4 {
+            if i <= chunk.len() {
+                output.push(char::from(
+                    BASE64_ALPHABET[(n >> (18 - 6 * i)) as usize & 0x3f],
+                ));
+            } else {
+                output.push('=');
+            }
+        }
+    }
+
+    output
+}
+
+/// Standard base64 decoding with optional padding
+///
+/// `None` if `input` contains invalid characters or has an invalid length.
+///
+pub(crate) fn decode(input: &str) -> Option<Vec<u8>> {
+    let input = input.trim_end_matches('=').as_bytes();
+    if input.len() % 4 == 1 {
+        return None;
+    }
+
+    let mut output = Vec::with_capacity(input.len() * 3 / 4);
+
+    for chunk in input.chunks(4) {
+        let mut n = 0_u32;
+        for (i, c) in chunk.iter().enumerate() {
+            let sextet = BASE64_ALPHABET.iter().position(|a| a == c)?;
+            #[allow(clippy::cast_possible_truncation)]
+            {
+                n |= (sextet as u32) << (18 - 6 * i);
+            }
+        }
+
+        let bytes = n.to_be_bytes();
+        output.extend_from_slice(&bytes[1..chunk.len()]);
+    }
+
+    Some(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode, encode};
+
+    #[test]
+    fn encode_test() {
+        assert_eq!(encode(b""), "");
+        assert_eq!(encode(b"f"), "Zg==");
+        assert_eq!(encode(b"fo"), "Zm8=");
+        assert_eq!(encode(b"foo"), "Zm9v");
+        assert_eq!(encode(b"foob"), "Zm9vYg==");
+        assert_eq!(encode(b"fooba"), "Zm9vYmE=");
+        assert_eq!(encode(b"foobar"), "Zm9vYmFy");
+        assert_eq!(encode(b"user:password"), "dXNlcjpwYXNzd29yZA==");
+    }
+
+    #[test]
+    fn decode_test() {
+        assert_eq!(decode("").unwrap(), b"");
+        assert_eq!(decode("Zg==").unwrap(), b"f");
+        assert_eq!(decode("Zm8=").unwrap(), b"fo");
+        assert_eq!(decode("Zm9v").unwrap(), b"foo");
+        assert_eq!(decode("Zm9vYg").unwrap(), b"foob");
+        assert_eq!(decode("Zm9vYmFy").unwrap(), b"foobar");
+        assert_eq!(decode("AP8=").unwrap(), b"\x00\xff");
+        assert!(decode("Zm9vY").is_none());
+        assert!(decode("Zm9*").is_none());
+    }
+}
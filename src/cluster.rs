@@ -0,0 +1,207 @@
+//! `Remove` state for `Query<State>` to manage the membership of the cluster
+
+use std::ops::Deref;
+
+use crate::{state::State, Connection, Query};
+
+/// _Remove_ a node from the cluster
+///
+/// A node, which is permanently gone, should be removed, so that the cluster keeps its quorum.
+/// The request is forwarded to the leader of the cluster.
+///
+/// See <https://rqlite.io/docs/clustering/general-guidelines/#removing-or-replacing-a-node>
+///
+#[derive(Debug, Eq, PartialEq)]
+pub struct Remove;
+impl State for Remove {}
+
+impl<C> Query<'_, Remove, C>
+where
+    C: Deref<Target = Connection>,
+{
+    /// Run `Request` to remove the node from the cluster
+    ///
+    /// If the [`Connection`](crate::Connection) has multiple nodes, the next node is tried
+    /// on connection errors. HTTP status 5xx is returned, because the node may be removed
+    /// already. There is no retry with a [`RetryPolicy`](crate::RetryPolicy).
+    ///
+    /// ```no_run
+    /// use rqlite_client::{Connection, ErrorKind};
+    ///
+    /// # #[cfg(feature = "url")]
+    /// let connection = Connection::new("http://localhost:4001").expect("url failed");
+    /// # #[cfg(not(feature = "url"))]
+    /// # let connection = Connection::new("http://localhost:4001");
+    ///
+    /// # #[cfg(any(feature = "reqwest", feature = "ureq"))]
+    /// match connection.remove_node("node3").request_remove() {
+    ///     Ok(()) => println!("node3 removed"),
+    ///     Err(err) if err.kind() == Some(ErrorKind::NotLeader) => println!("retry later"),
+    ///     Err(err) => panic!("remove failed: {err}"),
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// [Error](crate::Error) on failing [Request](crate::request::Request) run.\
+    /// [`Error::ResultError`](crate::Error::ResultError) classified as
+    /// [`ErrorKind::NodeNotFound`](crate::ErrorKind::NodeNotFound) or
+    /// [`ErrorKind::NotLeader`](crate::ErrorKind::NotLeader), if rqlite reports it.
+    ///
+    #[cfg(any(feature = "reqwest", feature = "ureq"))]
+    pub fn request_remove(&self) -> Result<(), crate::Error> {
+        let id = self.node_id();
+
+        match self.request_delete(&serde_json::json!({ "id": id })) {
+            Ok(_) => Ok(()),
+            Err(crate::Error::HttpError(status, msg)) => Err(remove_error(id, status, msg)),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Run `Request` to remove the node from the cluster and verify the removal
+    /// with [`monitor::Nodes`](crate::monitor::Nodes)
+    ///
+    /// Requires feature `monitor`.
+    ///
+    /// The nodes of the cluster are requested before and after the removal.
+    ///
+    /// # Errors
+    ///
+    /// [Error](crate::Error) like [`Query::request_remove()`](crate::Query::request_remove).\
+    /// [`Error::ResultError`](crate::Error::ResultError) classified as
+    /// [`ErrorKind::NodeNotFound`](crate::ErrorKind::NodeNotFound), if the node isn't member
+    /// of the cluster, or with `node not removed`, if the node is still member after the removal.
+    ///
+    #[cfg(feature = "monitor")]
+    #[cfg(any(feature = "reqwest", feature = "ureq"))]
+    pub fn request_remove_verified(&self) -> Result<(), crate::Error> {
+        let id = self.node_id();
+
+        if !self.is_node_member(id)? {
+            return Err(crate::Error::ResultError(format!("node not found: {id}")));
+        }
+
+        self.request_remove()?;
+
+        if self.is_node_member(id)? {
+            Err(crate::Error::ResultError(format!("node not removed: {id}")))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Id of the node to remove
+    fn node_id(&self) -> &str {
+        self.sql()
+            .first()
+            .and_then(|args| args.get("id"))
+            .and_then(crate::Value::as_str)
+            .unwrap_or_default()
+    }
+
+    /// Check with [`monitor::Nodes`](crate::monitor::Nodes) for a node with `id` in the cluster
+    #[cfg(feature = "monitor")]
+    #[cfg(any(feature = "reqwest", feature = "ureq"))]
+    fn is_node_member(&self, id: &str) -> Result<bool, crate::Error> {
+        let nodes = self
+            .connection_ref()
+            .monitor()
+            .nodes()
+            .enable_nonvoters()
+            .request_run_typed()?;
+
+        Ok(nodes.contains_key(id) || nodes.values().any(|node| node.id == id))
+    }
+}
+
+/// Convert the failed HTTP response of a removal into an [`Error`](crate::Error)
+///
+/// Messages of a missing node or a node, which isn't the leader, are converted into a
+/// [`Error::ResultError`](crate::Error::ResultError), which is classified by
+/// [`ErrorKind`](crate::ErrorKind). Other failures like HTTP status 503 without a
+/// _not leader_ message stay a [`Error::HttpError`](crate::Error::HttpError).
+///
+#[cfg(any(feature = "reqwest", feature = "ureq"))]
+fn remove_error(id: &str, status: u16, msg: String) -> crate::Error {
+    let lower = msg.to_ascii_lowercase();
+
+    if status == 404 || lower.contains("not found") || lower.contains("does not exist") {
+        crate::Error::ResultError(format!("node not found: {id}"))
+    } else if lower.starts_with("not leader") || lower.starts_with("leadership lost") {
+        crate::Error::ResultError(msg)
+    } else {
+        crate::Error::HttpError(status, msg)
+    }
+}
+
+#[cfg(test)]
+#[cfg(any(feature = "percent_encoding", feature = "url"))]
+mod tests {
+    use std::{sync::OnceLock, time::Duration};
+
+    use crate::Connection;
+
+    const TEST_CONNECTION_URL: &str = "http://localhost:4001/";
+
+    static TEST_CONNECTION: OnceLock<Connection> = OnceLock::new();
+
+    fn test_connection() -> &'static Connection {
+        TEST_CONNECTION.get_or_init(|| {
+            #[cfg(feature = "url")]
+            let c = Connection::new(TEST_CONNECTION_URL).unwrap();
+            #[cfg(not(feature = "url"))]
+            let c = Connection::new(TEST_CONNECTION_URL);
+
+            c
+        })
+    }
+
+    #[test]
+    fn remove_test() {
+        let mut q = test_connection().remove_node("node2");
+
+        assert_eq!(&q.create_path_with_query(), "/remove");
+        assert_eq!(q.node_id(), "node2");
+
+        q = q.set_timeout(Duration::from_secs(3).into());
+        assert_eq!(&q.create_path_with_query(), "/remove?timeout=3s");
+    }
+
+    #[cfg(any(feature = "reqwest", feature = "ureq"))]
+    #[test]
+    fn remove_error_test() {
+        use super::remove_error;
+        use crate::ErrorKind;
+
+        assert_eq!(
+            remove_error("node2", 404, "Not Found".to_string()).kind(),
+            Some(ErrorKind::NodeNotFound("node2".to_string()))
+        );
+        assert_eq!(
+            remove_error("node2", 500, "node node2 does not exist".to_string()).kind(),
+            Some(ErrorKind::NodeNotFound("node2".to_string()))
+        );
+        assert_eq!(
+            remove_error("node2", 500, "not leader".to_string()).kind(),
+            Some(ErrorKind::NotLeader)
+        );
+        assert_eq!(
+            remove_error(
+                "node2",
+                503,
+                "leadership lost while committing log".to_string()
+            )
+            .kind(),
+            Some(ErrorKind::NotLeader)
+        );
+        assert!(matches!(
+            remove_error("node2", 503, "Service Unavailable".to_string()),
+            crate::Error::HttpError(503, _)
+        ));
+        assert!(matches!(
+            remove_error("node2", 500, "raft failure".to_string()),
+            crate::Error::HttpError(500, _)
+        ));
+    }
+}
@@ -0,0 +1,115 @@
+//! `auth`
+
+use super::redaction::REDACTED;
+
+/// Authentication [`Auth`] of [`Connection`](crate::Connection)
+///
+/// The credentials are sent with every request as HTTP header and are never part of the url.
+/// [`Debug`] output redacts the secrets.
+///
+/// See <https://rqlite.io/docs/guides/security/#basic-auth>
+///
+#[derive(Clone, Eq, PartialEq)]
+pub enum Auth {
+    /// HTTP Basic authentication with `user` and `password`
+    Basic {
+        /// name of user
+        user: String,
+        /// password of user
+        password: String,
+    },
+    /// HTTP Bearer authentication with token
+    Bearer(String),
+    /// Custom HTTP header with name and value
+    Header(String, String),
+}
+
+impl Auth {
+    /// Name of HTTP header
+    #[must_use]
+    pub fn header_name(&self) -> &str {
+        match self {
+            Auth::Basic { .. } | Auth::Bearer(_) => "Authorization",
+            Auth::Header(name, _) => name,
+        }
+    }
+
+    /// Value of HTTP header
+    #[must_use]
+    pub fn header_value(&self) -> String {
+        match self {
+            Auth::Basic { user, password } => {
+                format!(
+                    "Basic {}",
+                    crate::base64::encode(format!("{user}:{password}").as_bytes())
+                )
+            }
+            Auth::Bearer(token) => format!("Bearer {token}"),
+            Auth::Header(_, value) => value.clone(),
+        }
+    }
+}
+
+impl std::fmt::Debug for Auth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Auth::Basic { user, .. } => f
+                .debug_struct("Basic")
+                .field("user", user)
+                .field("password", &REDACTED)
+                .finish(),
+            Auth::Bearer(_) => f.debug_tuple("Bearer").field(&REDACTED).finish(),
+            Auth::Header(name, _) => f
+                .debug_tuple("Header")
+                .field(name)
+                .field(&REDACTED)
+                .finish(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Auth;
+
+    #[test]
+    fn header_test() {
+        let auth = Auth::Basic {
+            user: "user".to_string(),
+            password: "password".to_string(),
+        };
+        assert_eq!(auth.header_name(), "Authorization");
+        assert_eq!(auth.header_value(), "Basic dXNlcjpwYXNzd29yZA==");
+
+        let auth = Auth::Bearer("token".to_string());
+        assert_eq!(auth.header_name(), "Authorization");
+        assert_eq!(auth.header_value(), "Bearer token");
+
+        let auth = Auth::Header("X-Api-Key".to_string(), "key".to_string());
+        assert_eq!(auth.header_name(), "X-Api-Key");
+        assert_eq!(auth.header_value(), "key");
+    }
+
+    #[test]
+    fn debug_redacted_test() {
+        let auth = Auth::Basic {
+            user: "user".to_string(),
+            password: "password".to_string(),
+        };
+        assert_eq!(
+            format!("{auth:?}"),
+            "Basic { user: \"user\", password: \"***\" }"
+        );
+        assert_eq!(
+            format!("{:?}", Auth::Bearer("token".to_string())),
+            "Bearer(\"***\")"
+        );
+        assert_eq!(
+            format!(
+                "{:?}",
+                Auth::Header("X-Api-Key".to_string(), "key".to_string())
+            ),
+            "Header(\"X-Api-Key\", \"***\")"
+        );
+    }
+}
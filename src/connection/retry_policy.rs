@@ -0,0 +1,328 @@
+//! `retry_policy`
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+use crate::endpoint::Endpoint;
+
+/// Classes of transient errors, which can be retried by [`RetryPolicy`]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum RetryOn {
+    /// Connection to node failed (including DNS lookup)
+    Connect,
+    /// Other transport errors like timeouts or broken connections
+    Io,
+    /// HTTP status 5xx except 503
+    ServerError,
+    /// HTTP status 503, e.g. during leader election
+    ServiceUnavailable,
+}
+
+/// [`RetryPolicy`] with exponential backoff for transient errors
+///
+/// The policy can be attached to [`Connection`](crate::Connection) with
+/// [`Connection::set_retry_policy()`](crate::Connection::set_retry_policy) or
+/// to single [`Query`](crate::Query) with [`Query::set_retry_policy()`](crate::Query::set_retry_policy).
+/// It is used by [`Query::request_run()`](crate::Query::request_run).
+///
+/// Reads are retried freely. Writes like [`Endpoint::Execute`] and [`Endpoint::Request`] are
+/// only retried, when it is safe to do so. This is the case, if the connection to the node
+/// failed, for queued writes ([`Query::is_queue()`](crate::Query::is_queue)) and for queries
+/// marked as idempotent with [`Query::set_idempotent()`](crate::Query::set_idempotent).
+/// The same check is used for the failover to the next node and for the retry on the leader
+/// of the cluster.
+///
+/// # Usage
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use rqlite_client::{Connection, RetryOn, RetryPolicy};
+///
+/// let policy = RetryPolicy::new()
+///     .set_max_attempts(5)
+///     .set_backoff(Duration::from_millis(50))
+///     .set_retry_on(&[RetryOn::Connect, RetryOn::ServiceUnavailable]);
+///
+/// let con = Connection::new("http://localhost:4001");
+/// #[cfg(feature = "url")]
+/// let con = con.unwrap();
+/// let con = con.set_retry_policy(policy);
+/// ```
+///
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RetryPolicy {
+    backoff: Duration,
+    backoff_max: Duration,
+    endpoints: Option<Vec<Endpoint>>,
+    is_jitter: bool,
+    max_attempts: u32,
+    retry_on: Vec<RetryOn>,
+}
+
+impl RetryPolicy {
+    /// Create new `RetryPolicy` with defaults
+    ///
+    /// * 3 attempts
+    /// * backoff starting with 100ms up to 2s
+    /// * jitter enabled
+    /// * retry on [`RetryOn::Connect`], [`RetryOn::Io`] and [`RetryOn::ServiceUnavailable`]
+    /// * all endpoints
+    ///
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            backoff: DEFAULT_BACKOFF,
+            backoff_max: DEFAULT_BACKOFF_MAX,
+            endpoints: None,
+            is_jitter: true,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            retry_on: vec![RetryOn::Connect, RetryOn::Io, RetryOn::ServiceUnavailable],
+        }
+    }
+
+    /// Initial `backoff` delay before the second attempt
+    #[must_use]
+    #[inline]
+    pub fn backoff(&self) -> Duration {
+        self.backoff
+    }
+
+    /// Maximum `backoff` delay between attempts
+    #[must_use]
+    #[inline]
+    pub fn backoff_max(&self) -> Duration {
+        self.backoff_max
+    }
+
+    /// Delay before next attempt after failed `attempt`
+    ///
+    /// The delay is doubled with every attempt up to [`RetryPolicy::backoff_max()`].
+    /// With jitter the delay is reduced randomly down to the half.
+    ///
+    #[must_use]
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let factor = 2_u32.saturating_pow(attempt.saturating_sub(1));
+        let delay = self.backoff.saturating_mul(factor).min(self.backoff_max);
+
+        if self.is_jitter {
+            jitter(delay)
+        } else {
+            delay
+        }
+    }
+
+    /// Disable random jitter of backoff delay
+    #[must_use]
+    #[inline]
+    pub fn disable_jitter(mut self) -> Self {
+        self.is_jitter = false;
+        self
+    }
+
+    /// Enable random jitter of backoff delay
+    #[must_use]
+    #[inline]
+    pub fn enable_jitter(mut self) -> Self {
+        self.is_jitter = true;
+        self
+    }
+
+    /// Retryable [`Endpoint`]s or `None` for all endpoints
+    #[must_use]
+    #[inline]
+    pub fn endpoints(&self) -> Option<&[Endpoint]> {
+        self.endpoints.as_deref()
+    }
+
+    /// Check for enabled random jitter of backoff delay
+    #[must_use]
+    #[inline]
+    pub fn is_jitter(&self) -> bool {
+        self.is_jitter
+    }
+
+    /// Maximum count of attempts including the first one
+    #[must_use]
+    #[inline]
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Error classes, which are retried
+    #[must_use]
+    #[inline]
+    pub fn retry_on(&self) -> &[RetryOn] {
+        &self.retry_on
+    }
+
+    /// Set initial `backoff` delay before the second attempt
+    #[must_use]
+    #[inline]
+    pub fn set_backoff(mut self, backoff: Duration) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    /// Set maximum `backoff` delay between attempts
+    #[must_use]
+    #[inline]
+    pub fn set_backoff_max(mut self, backoff_max: Duration) -> Self {
+        self.backoff_max = backoff_max;
+        self
+    }
+
+    /// Set retryable [`Endpoint`]s
+    #[must_use]
+    #[inline]
+    pub fn set_endpoints(mut self, endpoints: &[Endpoint]) -> Self {
+        self.endpoints = Some(endpoints.to_vec());
+        self
+    }
+
+    /// Set maximum count of attempts including the first one
+    ///
+    /// `0` is handled like `1`, which disables retries.
+    ///
+    #[must_use]
+    #[inline]
+    pub fn set_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Set error classes, which are retried
+    #[must_use]
+    #[inline]
+    pub fn set_retry_on(mut self, retry_on: &[RetryOn]) -> Self {
+        self.retry_on = retry_on.to_vec();
+        self
+    }
+
+    /// Check if `err` of request to `endpoint` should be retried after failed `attempt`
+    ///
+    /// `is_write_safe` needs to be `true` for retries of writes.
+    ///
+    pub(crate) fn is_retryable(
+        &self,
+        attempt: u32,
+        endpoint: Endpoint,
+        is_write_safe: bool,
+        err: &crate::Error,
+    ) -> bool {
+        if attempt >= self.max_attempts {
+            return false;
+        }
+
+        if let Some(endpoints) = &self.endpoints {
+            if !endpoints.contains(&endpoint) {
+                return false;
+            }
+        }
+
+        if !Self::is_resend_safe(endpoint, is_write_safe, err) {
+            return false;
+        }
+
+        err.retry_on()
+            .is_some_and(|retry_on| self.retry_on.contains(&retry_on))
+    }
+
+    /// Check if a request to `endpoint` can be sent again after `err`
+    ///
+    /// A request, which failed to connect, hasn't been sent. Writes, which may already be
+    /// applied, need `is_write_safe`.
+    ///
+    #[inline]
+    pub(crate) fn is_resend_safe(
+        endpoint: Endpoint,
+        is_write_safe: bool,
+        err: &crate::Error,
+    ) -> bool {
+        err.is_connect_failure() || !endpoint.is_write() || is_write_safe
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+const DEFAULT_BACKOFF: Duration = Duration::from_millis(100);
+const DEFAULT_BACKOFF_MAX: Duration = Duration::from_secs(2);
+const DEFAULT_MAX_ATTEMPTS: u32 = 3;
+
+/// Reduce `delay` randomly down to the half
+fn jitter(delay: Duration) -> Duration {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u128(delay.as_nanos());
+
+    let half = delay / 2;
+    let range = u64::try_from(half.as_nanos()).unwrap_or(u64::MAX);
+
+    half + Duration::from_nanos(hasher.finish() % range.saturating_add(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{RetryOn, RetryPolicy};
+    use crate::{endpoint::Endpoint, Error};
+
+    #[test]
+    fn delay_test() {
+        let policy = RetryPolicy::new()
+            .disable_jitter()
+            .set_backoff(Duration::from_millis(100))
+            .set_backoff_max(Duration::from_millis(500));
+
+        assert_eq!(policy.delay(1), Duration::from_millis(100));
+        assert_eq!(policy.delay(2), Duration::from_millis(200));
+        assert_eq!(policy.delay(3), Duration::from_millis(400));
+        assert_eq!(policy.delay(4), Duration::from_millis(500));
+        assert_eq!(policy.delay(100), Duration::from_millis(500));
+
+        let policy = policy.enable_jitter();
+        for attempt in 1..5 {
+            let delay = policy.delay(attempt);
+            let max = policy.clone().disable_jitter().delay(attempt);
+            assert!(delay >= max / 2 && delay <= max, "{delay:?} {max:?}");
+        }
+    }
+
+    #[test]
+    fn is_retryable_test() {
+        let policy = RetryPolicy::new();
+        let err = Error::HttpError(503, "Service Unavailable".to_string());
+
+        assert!(policy.is_retryable(1, Endpoint::Query, false, &err));
+        assert!(policy.is_retryable(2, Endpoint::Query, false, &err));
+        assert!(!policy.is_retryable(3, Endpoint::Query, false, &err));
+
+        assert!(!policy.is_retryable(1, Endpoint::Execute, false, &err));
+        assert!(policy.is_retryable(1, Endpoint::Execute, true, &err));
+        assert!(!policy.is_retryable(1, Endpoint::Request, false, &err));
+        assert!(!policy.is_retryable(1, Endpoint::Load, false, &err));
+        assert!(!policy.is_retryable(1, Endpoint::Remove, false, &err));
+        assert!(policy.is_retryable(1, Endpoint::Backup, false, &err));
+
+        let err = Error::HttpError(500, "Internal Server Error".to_string());
+        assert!(!policy.is_retryable(1, Endpoint::Query, false, &err));
+        let policy = policy.set_retry_on(&[RetryOn::ServerError]);
+        assert!(policy.is_retryable(1, Endpoint::Query, false, &err));
+
+        let err = Error::HttpError(400, "Bad Request".to_string());
+        assert!(!policy.is_retryable(1, Endpoint::Query, false, &err));
+
+        let err = Error::HttpError(500, "Internal Server Error".to_string());
+        let policy = policy.set_endpoints(&[Endpoint::Execute]);
+        assert!(!policy.is_retryable(1, Endpoint::Query, false, &err));
+        assert!(policy.is_retryable(1, Endpoint::Execute, true, &err));
+
+        let policy = policy.set_max_attempts(0);
+        assert!(!policy.is_retryable(1, Endpoint::Execute, true, &err));
+    }
+}
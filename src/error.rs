@@ -3,6 +3,8 @@
 #[cfg(feature = "migration")]
 use crate::migration::MigrationError;
 
+use crate::{ErrorKind, RetryOn};
+
 /// All obtainable [`Error`] values
 #[derive(Debug)]
 pub enum Error {
@@ -16,6 +18,10 @@ pub enum Error {
     #[cfg(feature = "migration")]
     MigrationError(MigrationError),
 
+    /// [`reqwest::Error`] (required feature _reqwest_)
+    #[cfg(feature = "reqwest")]
+    ReqwestError(Box<reqwest::Error>),
+
     /// `Error`s ocurred during response handling
     ResponseError(crate::Value),
 
@@ -34,6 +40,62 @@ pub enum Error {
     UrlParseError(url::ParseError),
 }
 
+impl Error {
+    /// Get [`ErrorKind`] of a failed SQL statement
+    ///
+    /// It is classified from the server error of [`Error::ResultError`] and of
+    /// [`MigrationError::QueryFail`] (required feature _migration_).
+    /// For all other errors and messages, which aren't a known server error, it is `None`.
+    ///
+    #[must_use]
+    pub fn kind(&self) -> Option<ErrorKind> {
+        match self {
+            #[cfg(feature = "migration")]
+            Error::MigrationError(err) => err.kind(),
+            Error::ResultError(msg) => ErrorKind::parse(msg),
+            _ => None,
+        }
+    }
+
+    /// Check for failure of the node, so that another node of the cluster should be tried
+    ///
+    /// These are connection errors and HTTP status 5xx.
+    ///
+    pub(crate) fn is_node_failure(&self) -> bool {
+        match self {
+            Error::HttpError(status, _) => *status >= 500,
+            _ => self.is_connect_failure(),
+        }
+    }
+
+    /// Check for failed connection to the node (including DNS lookup),
+    /// so that the request hasn't been sent
+    #[inline]
+    pub(crate) fn is_connect_failure(&self) -> bool {
+        self.retry_on() == Some(RetryOn::Connect)
+    }
+
+    /// Class of transient error for [`RetryPolicy`](crate::RetryPolicy) or `None` if not transient
+    pub(crate) fn retry_on(&self) -> Option<RetryOn> {
+        match self {
+            Error::HttpError(503, _) => Some(RetryOn::ServiceUnavailable),
+            Error::HttpError(status, _) if *status >= 500 => Some(RetryOn::ServerError),
+            Error::IoError(_) => Some(RetryOn::Io),
+            #[cfg(feature = "reqwest")]
+            Error::ReqwestError(err) if err.is_connect() => Some(RetryOn::Connect),
+            #[cfg(feature = "reqwest")]
+            Error::ReqwestError(err) if err.is_timeout() || err.is_request() => Some(RetryOn::Io),
+            #[cfg(feature = "ureq")]
+            Error::UreqError(err, _) => match err.kind() {
+                ureq::ErrorKind::ConnectionFailed | ureq::ErrorKind::Dns => Some(RetryOn::Connect),
+                ureq::ErrorKind::Io => Some(RetryOn::Io),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 impl std::error::Error for Error {}
 
 impl std::fmt::Display for Error {
@@ -48,6 +110,9 @@ impl std::fmt::Display for Error {
             #[cfg(feature = "migration")]
             Error::MigrationError(inner) => inner.fmt(f),
 
+            #[cfg(feature = "reqwest")]
+            Error::ReqwestError(inner) => inner.fmt(f),
+
             Error::ResponseError(v) => f.write_fmt(format_args!("response error: {v:?}")),
 
             Error::ResultError(msg) => f.write_str(msg),
@@ -69,6 +134,12 @@ impl From<&str> for Error {
     }
 }
 
+impl From<std::convert::Infallible> for Error {
+    fn from(value: std::convert::Infallible) -> Self {
+        match value {}
+    }
+}
+
 impl From<std::io::Error> for Error {
     fn from(value: std::io::Error) -> Self {
         Self::IoError(value)
@@ -88,6 +159,20 @@ impl From<serde_json::Error> for Error {
     }
 }
 
+#[cfg(feature = "reqwest")]
+impl From<reqwest::Error> for Error {
+    fn from(value: reqwest::Error) -> Self {
+        if let Some(status) = value.status() {
+            Self::HttpError(
+                status.as_u16(),
+                status.canonical_reason().unwrap_or_default().to_string(),
+            )
+        } else {
+            Self::ReqwestError(Box::new(value))
+        }
+    }
+}
+
 #[cfg(feature = "ureq")]
 impl From<ureq::Error> for Error {
     fn from(value: ureq::Error) -> Self {
@@ -0,0 +1,341 @@
+//! Classification of result errors with [`ErrorKind`]
+
+/// Classification of the error message of a failed SQL statement or cluster operation
+///
+/// __rqlite__ returns the errors of _SQLite_ only as message, e.g.
+/// `near "nonsense": syntax error` or `UNIQUE constraint failed: foo.name`.
+/// [`ErrorKind`] is parsed from such a message, so that there is no need to compare strings.
+///
+/// ```
+/// use rqlite_client::{ConstraintKind, ErrorKind};
+///
+/// assert_eq!(
+///     ErrorKind::from("UNIQUE constraint failed: foo.name"),
+///     ErrorKind::Constraint {
+///         kind: ConstraintKind::Unique,
+///         column: Some("foo.name".to_string())
+///     }
+/// );
+/// assert_eq!(ErrorKind::from("no such table: foo"), ErrorKind::NoSuchTable("foo".to_string()));
+/// ```
+///
+/// See [`response::mapping::Error::kind()`](crate::response::mapping::Error::kind) and
+/// [`Error::kind()`](crate::Error::kind)
+///
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ErrorKind {
+    /// Constraint violation with the column, if named in the message
+    Constraint {
+        /// kind of the constraint
+        kind: ConstraintKind,
+        /// column as `table.column` or name of the constraint
+        column: Option<String>,
+    },
+    /// Database or table is locked or busy
+    Locked,
+    /// Node with the id isn't member of the cluster
+    NodeNotFound(String),
+    /// Column doesn't exist
+    NoSuchColumn(String),
+    /// Table doesn't exist
+    NoSuchTable(String),
+    /// Node isn't the leader of the cluster
+    NotLeader,
+    /// Database is read-only
+    ReadOnly,
+    /// Syntax error in SQL statement
+    Syntax,
+    /// Timeout of the statement
+    Timeout,
+    /// Not classified error
+    Other,
+}
+
+impl ErrorKind {
+    /// Classify the error message of _SQLite_ or __rqlite__
+    ///
+    /// Only the fixed phrases of the messages are matched, so that `None` is returned
+    /// for any other message.
+    ///
+    pub(crate) fn parse(msg: &str) -> Option<Self> {
+        // ascii lowercase keeps the byte positions of `msg`
+        let lower = msg.to_ascii_lowercase();
+
+        let kind = if lower.ends_with(": syntax error")
+            || lower.contains(": syntax error ")
+            || lower.starts_with("incomplete input")
+            || lower.starts_with("unrecognized token: ")
+        {
+            Self::Syntax
+        } else if let Some(name) = name_after(msg, &lower, "no such table: ") {
+            Self::NoSuchTable(name)
+        } else if let Some(name) = name_after(msg, &lower, "no such column: ")
+            .or_else(|| name_after(msg, &lower, " has no column named "))
+        {
+            Self::NoSuchColumn(name)
+        } else if let Some(id) = name_after(msg, &lower, "node not found: ") {
+            Self::NodeNotFound(id)
+        } else if let Some(pos) = lower.find("constraint failed") {
+            Self::Constraint {
+                kind: ConstraintKind::from(&lower[..pos]),
+                column: name_after(msg, &lower, "constraint failed: "),
+            }
+        } else if lower.contains("attempt to write a readonly database") {
+            Self::ReadOnly
+        } else if lower.contains("database is locked")
+            || lower.contains("database table is locked")
+            || lower.contains("database schema is locked")
+            || msg.contains("SQLITE_BUSY")
+            || msg.contains("SQLITE_LOCKED")
+        {
+            Self::Locked
+        } else if lower.starts_with("not leader") || lower.starts_with("leadership lost") {
+            Self::NotLeader
+        } else if lower.contains("context deadline exceeded") || lower == "interrupted" {
+            Self::Timeout
+        } else {
+            return None;
+        };
+
+        Some(kind)
+    }
+}
+
+impl From<&str> for ErrorKind {
+    fn from(msg: &str) -> Self {
+        Self::parse(msg).unwrap_or(Self::Other)
+    }
+}
+
+impl std::fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Constraint {
+                kind,
+                column: Some(column),
+            } => f.write_fmt(format_args!("{kind} constraint failed: {column}")),
+            Self::Constraint { kind, column: None } => {
+                f.write_fmt(format_args!("{kind} constraint failed"))
+            }
+            Self::Locked => f.write_str("locked"),
+            Self::NodeNotFound(id) => f.write_fmt(format_args!("node not found: {id}")),
+            Self::NoSuchColumn(name) => f.write_fmt(format_args!("no such column: {name}")),
+            Self::NoSuchTable(name) => f.write_fmt(format_args!("no such table: {name}")),
+            Self::NotLeader => f.write_str("not leader"),
+            Self::ReadOnly => f.write_str("read-only"),
+            Self::Syntax => f.write_str("syntax error"),
+            Self::Timeout => f.write_str("timeout"),
+            Self::Other => f.write_str("other"),
+        }
+    }
+}
+
+/// Kind of the constraint of [`ErrorKind::Constraint`]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ConstraintKind {
+    /// `CHECK`
+    Check,
+    /// `FOREIGN KEY`
+    ForeignKey,
+    /// `NOT NULL`
+    NotNull,
+    /// `PRIMARY KEY`
+    PrimaryKey,
+    /// `UNIQUE`
+    Unique,
+    /// Not classified constraint
+    Other,
+}
+
+impl From<&str> for ConstraintKind {
+    /// Classify by the text in front of `constraint failed`
+    fn from(prefix: &str) -> Self {
+        let prefix = prefix.trim_end().to_ascii_lowercase();
+
+        if prefix.ends_with("unique") {
+            Self::Unique
+        } else if prefix.ends_with("not null") {
+            Self::NotNull
+        } else if prefix.ends_with("check") {
+            Self::Check
+        } else if prefix.ends_with("foreign key") {
+            Self::ForeignKey
+        } else if prefix.ends_with("primary key") {
+            Self::PrimaryKey
+        } else {
+            Self::Other
+        }
+    }
+}
+
+impl std::fmt::Display for ConstraintKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Check => f.write_str("CHECK"),
+            Self::ForeignKey => f.write_str("FOREIGN KEY"),
+            Self::NotNull => f.write_str("NOT NULL"),
+            Self::PrimaryKey => f.write_str("PRIMARY KEY"),
+            Self::Unique => f.write_str("UNIQUE"),
+            Self::Other => f.write_str("other"),
+        }
+    }
+}
+
+/// Name following `pattern` in `msg` up to the next whitespace or `,`
+///
+/// `lower` is the ascii lowercase `msg` to search for `pattern`.
+///
+fn name_after(msg: &str, lower: &str, pattern: &str) -> Option<String> {
+    let start = lower.find(pattern)? + pattern.len();
+    let name = msg[start..]
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .next()
+        .unwrap_or_default();
+
+    if name.is_empty() {
+        None
+    } else {
+        Some(name.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ConstraintKind, ErrorKind};
+
+    #[test]
+    fn syntax_test() {
+        assert_eq!(
+            ErrorKind::from("near \"nonsense\": syntax error"),
+            ErrorKind::Syntax
+        );
+        assert_eq!(ErrorKind::from("incomplete input"), ErrorKind::Syntax);
+    }
+
+    #[test]
+    fn no_such_test() {
+        assert_eq!(
+            ErrorKind::from("no such table: foo"),
+            ErrorKind::NoSuchTable("foo".to_string())
+        );
+        assert_eq!(
+            ErrorKind::from("no such column: bar - SELECT bar FROM foo"),
+            ErrorKind::NoSuchColumn("bar".to_string())
+        );
+    }
+
+    #[test]
+    fn constraint_test() {
+        assert_eq!(
+            ErrorKind::from("UNIQUE constraint failed: foo.name, foo.age"),
+            ErrorKind::Constraint {
+                kind: ConstraintKind::Unique,
+                column: Some("foo.name".to_string())
+            }
+        );
+        assert_eq!(
+            ErrorKind::from("NOT NULL constraint failed: foo.id"),
+            ErrorKind::Constraint {
+                kind: ConstraintKind::NotNull,
+                column: Some("foo.id".to_string())
+            }
+        );
+        assert_eq!(
+            ErrorKind::from("FOREIGN KEY constraint failed"),
+            ErrorKind::Constraint {
+                kind: ConstraintKind::ForeignKey,
+                column: None
+            }
+        );
+        assert_eq!(
+            ErrorKind::from("CHECK constraint failed: age > 0"),
+            ErrorKind::Constraint {
+                kind: ConstraintKind::Check,
+                column: Some("age".to_string())
+            }
+        );
+        assert_eq!(
+            ErrorKind::from("constraint failed").to_string(),
+            "other constraint failed"
+        );
+    }
+
+    #[test]
+    fn state_test() {
+        assert_eq!(ErrorKind::from("database is locked"), ErrorKind::Locked);
+        assert_eq!(
+            ErrorKind::from("database table is locked"),
+            ErrorKind::Locked
+        );
+        assert_eq!(
+            ErrorKind::from("attempt to write a readonly database"),
+            ErrorKind::ReadOnly
+        );
+        assert_eq!(ErrorKind::from("not leader"), ErrorKind::NotLeader);
+        assert_eq!(
+            ErrorKind::from("node not found: node2"),
+            ErrorKind::NodeNotFound("node2".to_string())
+        );
+        assert_eq!(
+            ErrorKind::from("context deadline exceeded"),
+            ErrorKind::Timeout
+        );
+        assert_eq!(ErrorKind::from("anything else"), ErrorKind::Other);
+    }
+
+    #[test]
+    fn anchored_test() {
+        assert_eq!(
+            ErrorKind::from("table foo has no column named bar"),
+            ErrorKind::NoSuchColumn("bar".to_string())
+        );
+        assert_eq!(
+            ErrorKind::from("unable to open database: SQLITE_BUSY"),
+            ErrorKind::Locked
+        );
+        assert_eq!(ErrorKind::from("busybox not available"), ErrorKind::Other);
+        assert_eq!(ErrorKind::from("no such user: timeout"), ErrorKind::Other);
+        assert_eq!(ErrorKind::from("connection timeout"), ErrorKind::Other);
+        assert_eq!(
+            ErrorKind::from("node is not leader-capable"),
+            ErrorKind::Other
+        );
+        assert_eq!(ErrorKind::parse("missing parameter name"), None);
+    }
+
+    #[test]
+    fn error_test() {
+        assert_eq!(
+            crate::Error::ResultError("no such table: foo".to_string()).kind(),
+            Some(ErrorKind::NoSuchTable("foo".to_string()))
+        );
+        assert_eq!(
+            crate::Error::ResultError("missing parameter timeout".to_string()).kind(),
+            None
+        );
+    }
+
+    #[cfg(feature = "migration")]
+    #[test]
+    fn migration_error_test() {
+        use crate::migration::MigrationError;
+
+        assert_eq!(
+            MigrationError::QueryFail("near \"x\": syntax error - x".to_string()).kind(),
+            Some(ErrorKind::Syntax)
+        );
+        assert_eq!(
+            MigrationError::QueryFail(
+                "table foo has no column named bar - INSERT INTO foo(bar) VALUES('database is locked')"
+                    .to_string()
+            )
+            .kind(),
+            Some(ErrorKind::NoSuchColumn("bar".to_string()))
+        );
+        assert_eq!(
+            MigrationError::QueryFail("result not handled - SELECT 'not leader'".to_string())
+                .kind(),
+            None
+        );
+    }
+}
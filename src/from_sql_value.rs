@@ -0,0 +1,304 @@
+//! Conversion of result values with [`FromSqlValue`]
+
+use crate::{DataType, Error, Value};
+
+/// Conversion of a result [`Value`] of a column with [`DataType`] into a Rust type
+///
+/// The [`DataType`] of the column is used to decide about the conversion, if the JSON
+/// representation is ambiguous. E.g. a _blob_ is returned by __rqlite__ as base64 encoded string.
+///
+/// | SQL value | Rust type |
+/// |---|---|
+/// | boolean, integer `0` or `1` | `bool` |
+/// | integer | integers, `f32`, `f64` |
+/// | real | `f32`, `f64` |
+/// | text | `String` |
+/// | blob as base64 string or byte array, text | `Vec<u8>` |
+/// | `NULL` | `None` of `Option<T>` |
+/// | any | [`Value`] |
+///
+/// The compatibility of the [`DataType`] of a column is checked with [`FromSqlValue::is_compatible()`].
+/// E.g. an `integer` column isn't accepted for `String` and a `real` column isn't accepted for integers.
+///
+/// It is used by [`Row::try_get()`](crate::response::mapping::Row::try_get) and
+/// [`FromRow`](crate::FromRow) to decode the columns of a row.
+///
+pub trait FromSqlValue: Sized {
+    /// Convert `value` of a column with `data_type` into `Self`
+    ///
+    /// # Errors
+    ///
+    /// [`Error::ResultError`] if `value` can't be converted
+    fn from_sql_value(value: &Value, data_type: Option<DataType>) -> Result<Self, Error>;
+
+    /// Check the [`DataType`] of a column before the conversion of its value
+    ///
+    /// The default accepts every [`DataType`].
+    ///
+    #[must_use]
+    #[inline]
+    fn is_compatible(data_type: DataType) -> bool {
+        let _ = data_type;
+        true
+    }
+}
+
+impl<T> FromSqlValue for Option<T>
+where
+    T: FromSqlValue,
+{
+    fn from_sql_value(value: &Value, data_type: Option<DataType>) -> Result<Self, Error> {
+        if value.is_null() {
+            Ok(None)
+        } else {
+            T::from_sql_value(value, data_type).map(Some)
+        }
+    }
+
+    #[inline]
+    fn is_compatible(data_type: DataType) -> bool {
+        T::is_compatible(data_type)
+    }
+}
+
+impl FromSqlValue for Value {
+    #[inline]
+    fn from_sql_value(value: &Value, _data_type: Option<DataType>) -> Result<Self, Error> {
+        Ok(value.clone())
+    }
+}
+
+impl FromSqlValue for bool {
+    fn from_sql_value(value: &Value, data_type: Option<DataType>) -> Result<Self, Error> {
+        match value {
+            Value::Bool(b) => Ok(*b),
+            Value::Number(n) if n.as_i64() == Some(0) => Ok(false),
+            Value::Number(n) if n.as_i64() == Some(1) => Ok(true),
+            _ => Err(mismatch("bool", value, data_type)),
+        }
+    }
+
+    #[inline]
+    fn is_compatible(data_type: DataType) -> bool {
+        matches!(
+            data_type,
+            DataType::Boolean | DataType::Integer | DataType::Numeric
+        )
+    }
+}
+
+impl FromSqlValue for f64 {
+    fn from_sql_value(value: &Value, data_type: Option<DataType>) -> Result<Self, Error> {
+        value
+            .as_f64()
+            .ok_or_else(|| mismatch("f64", value, data_type))
+    }
+
+    #[inline]
+    fn is_compatible(data_type: DataType) -> bool {
+        is_number(data_type)
+    }
+}
+
+impl FromSqlValue for f32 {
+    #[allow(clippy::cast_possible_truncation)]
+    fn from_sql_value(value: &Value, data_type: Option<DataType>) -> Result<Self, Error> {
+        value
+            .as_f64()
+            .map(|f| f as f32)
+            .ok_or_else(|| mismatch("f32", value, data_type))
+    }
+
+    #[inline]
+    fn is_compatible(data_type: DataType) -> bool {
+        is_number(data_type)
+    }
+}
+
+impl FromSqlValue for String {
+    fn from_sql_value(value: &Value, data_type: Option<DataType>) -> Result<Self, Error> {
+        match value {
+            Value::String(s) => Ok(s.clone()),
+            Value::Number(n) if data_type == Some(DataType::Text) => Ok(n.to_string()),
+            _ => Err(mismatch("String", value, data_type)),
+        }
+    }
+
+    #[inline]
+    fn is_compatible(data_type: DataType) -> bool {
+        data_type == DataType::Text
+    }
+}
+
+impl FromSqlValue for Vec<u8> {
+    fn from_sql_value(value: &Value, data_type: Option<DataType>) -> Result<Self, Error> {
+        match value {
+            Value::String(s) if data_type == Some(DataType::Text) => Ok(s.as_bytes().to_vec()),
+            Value::String(s) => {
+                crate::base64::decode(s).ok_or_else(|| mismatch("Vec<u8>", value, data_type))
+            }
+            Value::Array(a) => a
+                .iter()
+                .map(|v| {
+                    v.as_u64()
+                        .and_then(|b| u8::try_from(b).ok())
+                        .ok_or_else(|| mismatch("Vec<u8>", value, data_type))
+                })
+                .collect(),
+            _ => Err(mismatch("Vec<u8>", value, data_type)),
+        }
+    }
+
+    #[inline]
+    fn is_compatible(data_type: DataType) -> bool {
+        matches!(data_type, DataType::Blob | DataType::Text)
+    }
+}
+
+/// Generate [`FromSqlValue`] impls for integers
+macro_rules! gen_from_sql_value_int {
+    ( $($t:ty),+ ) => {
+        $(
+            impl FromSqlValue for $t {
+                fn from_sql_value(value: &Value, data_type: Option<DataType>) -> Result<Self, Error> {
+                    let Value::Number(n) = value else {
+                        return Err(mismatch(stringify!($t), value, data_type));
+                    };
+
+                    if let Some(i) = n.as_i64() {
+                        <$t>::try_from(i).map_err(|_| out_of_range(stringify!($t), value))
+                    } else if let Some(u) = n.as_u64() {
+                        <$t>::try_from(u).map_err(|_| out_of_range(stringify!($t), value))
+                    } else {
+                        Err(mismatch(stringify!($t), value, data_type))
+                    }
+                }
+
+                #[inline]
+                fn is_compatible(data_type: DataType) -> bool {
+                    matches!(
+                        data_type,
+                        DataType::Boolean | DataType::Integer | DataType::Numeric
+                    )
+                }
+            }
+        )+
+    };
+}
+
+gen_from_sql_value_int!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+/// Check for a numeric [`DataType`]
+#[inline]
+fn is_number(data_type: DataType) -> bool {
+    matches!(
+        data_type,
+        DataType::Integer | DataType::Numeric | DataType::Real
+    )
+}
+
+/// Error for type mismatch of `value` and Rust type `name`
+fn mismatch(name: &str, value: &Value, data_type: Option<DataType>) -> Error {
+    match data_type {
+        Some(data_type) => {
+            Error::ResultError(format!("can't convert {data_type} value {value} to {name}"))
+        }
+        None => Error::ResultError(format!("can't convert value {value} to {name}")),
+    }
+}
+
+/// Error for `value` out of range of integer type `name`
+fn out_of_range(name: &str, value: &Value) -> Error {
+    Error::ResultError(format!("value {value} out of range of {name}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FromSqlValue;
+    use crate::{DataType, Value};
+
+    #[test]
+    fn integer_test() {
+        let v = Value::from(42);
+        assert_eq!(
+            i64::from_sql_value(&v, Some(DataType::Integer)).unwrap(),
+            42
+        );
+        assert_eq!(u8::from_sql_value(&v, None).unwrap(), 42);
+        assert!((f64::from_sql_value(&v, None).unwrap() - 42.0).abs() < f64::EPSILON);
+
+        let v = Value::from(-1);
+        let err = u32::from_sql_value(&v, None).unwrap_err();
+        assert_eq!(err.to_string(), "value -1 out of range of u32");
+
+        let v = Value::from(1.5);
+        let err = i64::from_sql_value(&v, Some(DataType::Real)).unwrap_err();
+        assert_eq!(err.to_string(), "can't convert real value 1.5 to i64");
+
+        let v = Value::from("42");
+        assert!(i64::from_sql_value(&v, Some(DataType::Text)).is_err());
+    }
+
+    #[test]
+    fn bool_test() {
+        assert!(bool::from_sql_value(&Value::Bool(true), None).unwrap());
+        assert!(bool::from_sql_value(&Value::from(1), Some(DataType::Boolean)).unwrap());
+        assert!(!bool::from_sql_value(&Value::from(0), Some(DataType::Boolean)).unwrap());
+        assert!(bool::from_sql_value(&Value::from(2), Some(DataType::Integer)).is_err());
+    }
+
+    #[test]
+    fn text_test() {
+        let v = Value::from("text");
+        assert_eq!(String::from_sql_value(&v, None).unwrap(), "text");
+        assert_eq!(
+            String::from_sql_value(&Value::from(1), Some(DataType::Text)).unwrap(),
+            "1"
+        );
+        assert!(String::from_sql_value(&Value::from(1), Some(DataType::Integer)).is_err());
+    }
+
+    #[test]
+    fn blob_test() {
+        let v = Value::from("AP8=");
+        assert_eq!(
+            Vec::<u8>::from_sql_value(&v, Some(DataType::Blob)).unwrap(),
+            b"\x00\xff"
+        );
+        assert_eq!(
+            Vec::<u8>::from_sql_value(&v, Some(DataType::Text)).unwrap(),
+            b"AP8="
+        );
+        assert_eq!(
+            Vec::<u8>::from_sql_value(&Value::from(vec![0, 255]), None).unwrap(),
+            b"\x00\xff"
+        );
+        assert!(Vec::<u8>::from_sql_value(&Value::from(vec![256]), None).is_err());
+    }
+
+    #[test]
+    fn is_compatible_test() {
+        assert!(i64::is_compatible(DataType::Integer));
+        assert!(!i64::is_compatible(DataType::Real));
+        assert!(f64::is_compatible(DataType::Integer));
+        assert!(bool::is_compatible(DataType::Boolean));
+        assert!(!String::is_compatible(DataType::Integer));
+        assert!(Vec::<u8>::is_compatible(DataType::Blob));
+        assert!(Option::<Vec<u8>>::is_compatible(DataType::Blob));
+        assert!(!Option::<Vec<u8>>::is_compatible(DataType::Real));
+        assert!(Value::is_compatible(DataType::Real));
+    }
+
+    #[test]
+    fn option_test() {
+        assert_eq!(
+            Option::<i64>::from_sql_value(&Value::Null, Some(DataType::Integer)).unwrap(),
+            None
+        );
+        assert_eq!(
+            Option::<i64>::from_sql_value(&Value::from(1), Some(DataType::Integer)).unwrap(),
+            Some(1)
+        );
+        assert!(i64::from_sql_value(&Value::Null, Some(DataType::Integer)).is_err());
+    }
+}
@@ -0,0 +1,185 @@
+//! `Load` and `Boot` states for `Query<State>`
+
+use std::ops::Deref;
+
+use crate::{backup::Format, state::State, Connection, Query};
+
+/// _Load_ restores the database from a _SQLite_ database file or a SQL text dump
+///
+/// The data is uploaded from any [`std::io::Read`] with
+/// [`Query::request_load()`](crate::Query::request_load), so that large databases
+/// are not held in memory.
+/// [`Query::request_run()`](crate::Query::request_run) fails for a _Load_.
+///
+/// See <https://rqlite.io/docs/guides/backup/#restoring-from-sqlite>
+///
+#[derive(Debug, Eq, PartialEq)]
+pub struct Load;
+impl State for Load {}
+
+/// _Boot_ initializes a single node with a _SQLite_ database file
+///
+/// Booting is the fastest way to load large datasets, but it is only supported
+/// by a single node, which isn't part of a cluster.
+///
+/// The data is uploaded from any [`std::io::Read`] with
+/// [`Query::request_boot()`](crate::Query::request_boot).
+/// [`Query::request_run()`](crate::Query::request_run) fails for a _Boot_.
+///
+/// See <https://rqlite.io/docs/guides/backup/#booting-with-a-sqlite-database>
+///
+#[derive(Debug, Eq, PartialEq)]
+pub struct Boot;
+impl State for Boot {}
+
+impl<C> Query<'_, Load, C>
+where
+    C: Deref<Target = Connection>,
+{
+    /// Set [`Format`] of the data to load, by default [`Format::Sqlite`]
+    ///
+    /// The data is sent with content type `application/octet-stream` for [`Format::Sqlite`]
+    /// and `text/plain` for [`Format::Sql`].
+    ///
+    #[must_use]
+    pub fn set_format(self, format: Format) -> Self {
+        self.set_format_helper(format)
+    }
+
+    /// Run `Request` uploading the data to load from `reader`
+    ///
+    /// The data is sent as it is read, so it is not held in memory.
+    ///
+    /// The response contains the results of the statements of a [`Format::Sql`] dump.
+    /// For a [`Format::Sqlite`] database file there are no results.
+    ///
+    /// The `Request` isn't repeated on other nodes or with a [`RetryPolicy`](crate::RetryPolicy),
+    /// because the `reader` is consumed.
+    ///
+    /// ```no_run
+    /// use rqlite_client::{backup::Format, Connection};
+    ///
+    /// # #[cfg(feature = "url")]
+    /// let connection = Connection::new("http://localhost:4001").expect("url failed");
+    /// # #[cfg(not(feature = "url"))]
+    /// # let connection = Connection::new("http://localhost:4001");
+    ///
+    /// let file = std::fs::File::open("backup.sql").expect("open failed");
+    ///
+    /// # #[cfg(any(feature = "reqwest", feature = "ureq"))]
+    /// let response = connection
+    ///     .load()
+    ///     .set_format(Format::Sql)
+    ///     .request_load(file)
+    ///     .expect("load failed");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// [Error](crate::Error) on failing [Request](crate::request::Request) run, failing read
+    /// from `reader` or [`Error::ResultError`](crate::Error::ResultError) with the message of
+    /// the first failed statement
+    ///
+    #[cfg(any(feature = "reqwest", feature = "ureq"))]
+    pub fn request_load<R>(&self, reader: R) -> Result<crate::response::Query, crate::Error>
+    where
+        R: std::io::Read + Send + 'static,
+    {
+        let response = match self.request_upload(reader)? {
+            Some(value) if value.get("results").is_some() => {
+                serde_json::from_value::<crate::response::Query>(value)?
+            }
+            _ => crate::response::Query::default(),
+        };
+
+        if let Some(err) = response.iter().find_map(|result| match result {
+            crate::Mapping::Error(err) => Some(err),
+            _ => None,
+        }) {
+            return Err(crate::Error::ResultError(err.error.clone()));
+        }
+
+        Ok(response)
+    }
+}
+
+impl<C> Query<'_, Boot, C>
+where
+    C: Deref<Target = Connection>,
+{
+    /// Run `Request` uploading the _SQLite_ database file to boot from `reader`
+    ///
+    /// The data is sent as it is read, so it is not held in memory.
+    ///
+    /// The `Request` isn't repeated on other nodes or with a [`RetryPolicy`](crate::RetryPolicy),
+    /// because the `reader` is consumed.
+    ///
+    /// # Errors
+    ///
+    /// [Error](crate::Error) on failing [Request](crate::request::Request) run or failing read
+    /// from `reader`
+    ///
+    #[cfg(any(feature = "reqwest", feature = "ureq"))]
+    pub fn request_boot<R>(&self, reader: R) -> Result<(), crate::Error>
+    where
+        R: std::io::Read + Send + 'static,
+    {
+        let _ = self.request_upload(reader)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[cfg(any(feature = "percent_encoding", feature = "url"))]
+mod tests {
+    use std::{sync::OnceLock, time::Duration};
+
+    use crate::{backup::Format, Connection};
+
+    const TEST_CONNECTION_URL: &str = "http://localhost:4001/";
+
+    static TEST_CONNECTION: OnceLock<Connection> = OnceLock::new();
+
+    fn test_connection() -> &'static Connection {
+        TEST_CONNECTION.get_or_init(|| {
+            #[cfg(feature = "url")]
+            let c = Connection::new(TEST_CONNECTION_URL).unwrap();
+            #[cfg(not(feature = "url"))]
+            let c = Connection::new(TEST_CONNECTION_URL);
+
+            c
+        })
+    }
+
+    #[test]
+    fn load_test() {
+        let mut q = test_connection().load();
+
+        assert_eq!(&q.create_path_with_query(), "/db/load");
+
+        q = q.set_format(Format::Sql);
+
+        assert_eq!(&q.create_path_with_query(), "/db/load");
+
+        q = q.set_timeout(Duration::from_secs(3).into());
+        assert_eq!(&q.create_path_with_query(), "/db/load?timeout=3s");
+    }
+
+    #[test]
+    fn boot_test() {
+        let q = test_connection().boot();
+
+        assert_eq!(&q.create_path_with_query(), "/boot");
+    }
+
+    #[cfg(any(feature = "reqwest", feature = "ureq"))]
+    #[test]
+    fn request_run_test() {
+        let err = test_connection().load().request_run().unwrap_err();
+        assert!(err.to_string().contains("request_load()"));
+
+        let err = test_connection().boot().request_run().unwrap_err();
+        assert!(err.to_string().contains("request_boot()"));
+    }
+}
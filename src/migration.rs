@@ -201,60 +201,16 @@ where
         connection: &Connection,
         to_version: Option<&SchemaVersion>,
     ) -> Result<SchemaVersion, MigrationError> {
-        if self.migrations.is_empty() {
-            return Err(MigrationError::NoData);
-        }
-        if self.request_builder.is_none() {
-            return Err(MigrationError::NoRequestBuilder);
-        }
-
-        let rb = self
-            .request_builder
-            .as_ref()
-            .expect("no request_builder checked and found");
-
-        let db_version = Self::pragma_user_version(connection, rb)?;
+        let rb = self.checked_request_builder()?;
 
-        // migrate from db_version onwards
-        let mut query = connection.execute().enable_transaction();
-
-        let mut version = SchemaVersion::default();
-
-        for (upgrade, _) in self.migrations.iter().map(Mtuple::from) {
-            if let Some(to_version) = to_version.filter(|v| &version >= *v) {
-                let _ = to_version;
-                log::trace!("db_version: {db_version} - migrated to version {to_version}");
-                tracing::trace!("db_version: {db_version} - migrated to version {to_version}");
-                break;
-            } else if db_version <= version {
-                log::debug!("db_version: {db_version} migrating: {version}");
-                tracing::debug!("db_version: {db_version} migrating: {version}");
-
-                for line in upgrade.lines() {
-                    query = query.push_sql(Value::from(line));
-                }
-            } else {
-                log::trace!("db_version: {db_version} - already migrated with version {version}");
-                tracing::trace!(
-                    "db_version: {db_version} - already migrated with version {version}"
-                );
-            }
-            version += 1;
-        }
+        let query = Self::pragma_user_version_query(connection);
+        let db_version = Self::pragma_user_version(&query, rb.run(&query))?;
 
-        if let Some(to_version) = to_version {
-            if version != *to_version {
-                return Err(MigrationError::DataMalformat(format!(
-                    "no migration {to_version}"
-                )));
-            }
-            if version < db_version {
-                version = db_version;
-            }
-        }
+        let (query, version) = self.migrate_query(connection, db_version, to_version)?;
 
         // at the end set new user_version
-        Self::run_n_set_pragma_user_version(rb, query, version)?;
+        let query = Self::set_pragma_user_version_query(query, version);
+        Self::check_results(&query, rb.run(&query))?;
 
         log::info!("migrated to version {version}");
         tracing::info!("migrated to version {version}");
@@ -269,30 +225,26 @@ where
         self.migrations.pop()
     }
 
-    /// Retrieve pragma `user_version` from DB
+    /// Retrieve pragma `user_version` from the `response` of `query`
     fn pragma_user_version(
-        connection: &Connection,
-        rb: &T,
+        query: &Query<'_, state::NoLevelMulti>,
+        response: crate::response::Result,
     ) -> Result<SchemaVersion, MigrationError> {
-        let query = connection.query().push_sql_str("PRAGMA user_version");
-
         // irrefutable_let_patterns: with no monitor feature
         #[allow(irrefutable_let_patterns)]
-        let Response::Query(r) = rb
-            .run(&query)
-            .map_err(|err| MigrationError::try_from(err).unwrap_err())?
+        let Response::Query(r) =
+            response.map_err(|err| MigrationError::try_from(err).unwrap_err())?
         else {
             return Err(MigrationError::Internal("query_response required"));
         };
 
         let mut db_version = None;
-        for (index, result) in r.results().enumerate() {
+        for (statement, _, result) in r.statement_results(query.sql()) {
             match result {
                 Mapping::Error(err) => {
                     return Err(MigrationError::QueryFail(format!(
-                        "{} - {}",
-                        err.error,
-                        query.sql()[index]
+                        "{} - {statement}",
+                        err.error
                     )));
                 }
                 Mapping::Standard(standard) => {
@@ -338,21 +290,119 @@ where
         connection: &Connection,
         to_version: &SchemaVersion,
     ) -> Result<SchemaVersion, MigrationError> {
+        let rb = self.checked_request_builder()?;
+
+        let query = Self::pragma_user_version_query(connection);
+        let db_version = Self::pragma_user_version(&query, rb.run(&query))?;
+
+        let (query, version) = self.rollback_query(connection, db_version, *to_version)?;
+
+        // at the end set new user_version
+        let query = Self::set_pragma_user_version_query(query, version);
+        Self::check_results(&query, rb.run(&query))?;
+
+        log::info!("rollback to version {version}");
+        tracing::info!("rollback to version {version}");
+
+        Ok(version)
+    }
+
+    /// Check the response of `query` for error results
+    fn check_results(
+        query: &Query<'_, state::NoLevelMulti>,
+        response: crate::response::Result,
+    ) -> Result<(), MigrationError> {
+        // irrefutable_let_patterns: with no monitor feature
+        #[allow(irrefutable_let_patterns)]
+        let Response::Query(r) =
+            response.map_err(|err| MigrationError::try_from(err).unwrap_err())?
+        else {
+            return Err(MigrationError::Internal("query response required"));
+        };
+
+        // check for error fields
+        if let Some((_, statement, err)) = r.first_error(query.sql()) {
+            return Err(MigrationError::QueryFail(format!(
+                "{} - {statement}",
+                err.error
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Check for available migrations and get the `request_builder`
+    fn checked_request_builder(&self) -> Result<&T, MigrationError> {
         if self.migrations.is_empty() {
             return Err(MigrationError::NoData);
         }
-        if self.request_builder.is_none() {
-            return Err(MigrationError::NoRequestBuilder);
-        }
 
-        let rb = self
-            .request_builder
+        self.request_builder
             .as_ref()
-            .expect("request_builder checked and found");
+            .ok_or(MigrationError::NoRequestBuilder)
+    }
 
-        let db_version = Self::pragma_user_version(connection, rb)?;
+    /// Build the upgrade `Query` from `db_version` onwards
+    fn migrate_query<'c>(
+        &self,
+        connection: &'c Connection,
+        db_version: SchemaVersion,
+        to_version: Option<&SchemaVersion>,
+    ) -> Result<(Query<'c, state::NoLevelMulti>, SchemaVersion), MigrationError> {
+        // migrate from db_version onwards
+        let mut query = connection.execute().enable_transaction();
+
+        let mut version = SchemaVersion::default();
+
+        for (upgrade, _) in self.migrations.iter().map(Mtuple::from) {
+            if let Some(to_version) = to_version.filter(|v| &version >= *v) {
+                let _ = to_version;
+                log::trace!("db_version: {db_version} - migrated to version {to_version}");
+                tracing::trace!("db_version: {db_version} - migrated to version {to_version}");
+                break;
+            } else if db_version <= version {
+                log::debug!("db_version: {db_version} migrating: {version}");
+                tracing::debug!("db_version: {db_version} migrating: {version}");
 
-        if *to_version >= db_version {
+                for line in upgrade.lines() {
+                    query = query.push_sql(Value::from(line));
+                }
+            } else {
+                log::trace!("db_version: {db_version} - already migrated with version {version}");
+                tracing::trace!(
+                    "db_version: {db_version} - already migrated with version {version}"
+                );
+            }
+            version += 1;
+        }
+
+        if let Some(to_version) = to_version {
+            if version != *to_version {
+                return Err(MigrationError::DataMalformat(format!(
+                    "no migration {to_version}"
+                )));
+            }
+            if version < db_version {
+                version = db_version;
+            }
+        }
+
+        Ok((query, version))
+    }
+
+    /// `Query` for pragma `user_version` from DB
+    fn pragma_user_version_query(connection: &Connection) -> Query<'_, state::NoLevelMulti> {
+        connection.query().push_sql_str("PRAGMA user_version")
+    }
+
+    /// Build the downgrade `Query` from `db_version` backwards
+    fn rollback_query<'c>(
+        &self,
+        connection: &'c Connection,
+        db_version: SchemaVersion,
+        to_version: SchemaVersion,
+    ) -> Result<(Query<'c, state::NoLevelMulti>, SchemaVersion), MigrationError> {
+        if to_version >= db_version {
             return Err(MigrationError::DataMalformat(format!(
                 "no rollback {to_version}"
             )));
@@ -364,7 +414,7 @@ where
         let mut version = db_version;
 
         for (_, downgrade) in self.migrations.iter().rev().map(Mtuple::from) {
-            if version > *to_version {
+            if version > to_version {
                 if let Some(downgrade) = downgrade {
                     version = version.checked_sub(1).unwrap_or_default();
 
@@ -385,45 +435,15 @@ where
             }
         }
 
-        // at the end set new user_version
-        Self::run_n_set_pragma_user_version(rb, query, version)?;
-
-        log::info!("rollback to version {version}");
-        tracing::info!("rollback to version {version}");
-
-        Ok(version)
+        Ok((query, version))
     }
 
-    /// Set pragma `user_version`
-    fn run_n_set_pragma_user_version(
-        rb: &T,
+    /// Append setting of pragma `user_version` to `query`
+    fn set_pragma_user_version_query(
         query: Query<'_, state::NoLevelMulti>,
         version: SchemaVersion,
-    ) -> Result<(), MigrationError> {
-        // at the end set new user_version
-        let query = query.push_sql_str(&format!("PRAGMA user_version={version}"));
-
-        // irrefutable_let_patterns: with no monitor feature
-        #[allow(irrefutable_let_patterns)]
-        let Response::Query(r) = rb
-            .run(&query)
-            .map_err(|err| MigrationError::try_from(err).unwrap_err())?
-        else {
-            return Err(MigrationError::Internal("query response required"));
-        };
-
-        // check for error fields
-        for (index, result) in r.results().enumerate() {
-            if let Mapping::Error(err) = result {
-                return Err(MigrationError::QueryFail(format!(
-                    "{} - {}",
-                    err.error,
-                    query.sql()[index]
-                )));
-            }
-        }
-
-        Ok(())
+    ) -> Query<'_, state::NoLevelMulti> {
+        query.push_sql_str(&format!("PRAGMA user_version={version}"))
     }
 
     /// Set `request_builder` to execute [`Migration`]
@@ -496,6 +516,99 @@ where
     }
 }
 
+#[cfg(any(feature = "reqwest", feature = "tokio"))]
+impl<T> Migration<'_, T>
+where
+    T: RequestBuilder<state::NoLevelMulti> + crate::AsyncRequestBuilder<state::NoLevelMulti>,
+{
+    /// Migrate provided `Migration` asynchronously
+    ///
+    /// See [`Migration::migrate()`].
+    ///
+    /// Requires feature `reqwest` or `tokio`.
+    ///
+    /// # Errors
+    ///
+    /// [`MigrationError`] on failed `Migration`
+    ///
+    #[inline]
+    pub async fn migrate_async(
+        &self,
+        connection: &Connection,
+    ) -> Result<SchemaVersion, MigrationError> {
+        self.migrate_to_async(connection, None::<&SchemaVersion>)
+            .await
+    }
+
+    /// Migrate provided `Migration` asynchronously to provided [`SchemaVersion`]
+    ///
+    /// See [`Migration::migrate_to()`].
+    ///
+    /// Requires feature `reqwest` or `tokio`.
+    ///
+    /// # Errors
+    ///
+    /// [`MigrationError`] on failed `Migration`
+    ///
+    pub async fn migrate_to_async(
+        &self,
+        connection: &Connection,
+        to_version: Option<&SchemaVersion>,
+    ) -> Result<SchemaVersion, MigrationError> {
+        let rb = self.checked_request_builder()?;
+
+        let query = Self::pragma_user_version_query(connection);
+        let future = rb.run_async(&query);
+        let db_version = Self::pragma_user_version(&query, future.await)?;
+
+        let (query, version) = self.migrate_query(connection, db_version, to_version)?;
+
+        // at the end set new user_version
+        let query = Self::set_pragma_user_version_query(query, version);
+        let future = rb.run_async(&query);
+        Self::check_results(&query, future.await)?;
+
+        log::info!("migrated to version {version}");
+        tracing::info!("migrated to version {version}");
+
+        Ok(version)
+    }
+
+    /// Rollback provided `Migration` asynchronously to provided [`SchemaVersion`]
+    ///
+    /// See [`Migration::rollback_to()`].
+    ///
+    /// Requires feature `reqwest` or `tokio`.
+    ///
+    /// # Errors
+    ///
+    /// [`MigrationError`] on failed `Migration` rollback
+    ///
+    pub async fn rollback_to_async(
+        &self,
+        connection: &Connection,
+        to_version: &SchemaVersion,
+    ) -> Result<SchemaVersion, MigrationError> {
+        let rb = self.checked_request_builder()?;
+
+        let query = Self::pragma_user_version_query(connection);
+        let future = rb.run_async(&query);
+        let db_version = Self::pragma_user_version(&query, future.await)?;
+
+        let (query, version) = self.rollback_query(connection, db_version, *to_version)?;
+
+        // at the end set new user_version
+        let query = Self::set_pragma_user_version_query(query, version);
+        let future = rb.run_async(&query);
+        Self::check_results(&query, future.await)?;
+
+        log::info!("rollback to version {version}");
+        tracing::info!("rollback to version {version}");
+
+        Ok(version)
+    }
+}
+
 impl<'a, T> std::ops::Add for Migration<'a, T>
 where
     T: RequestBuilder<state::NoLevelMulti> + Clone,
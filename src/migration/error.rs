@@ -1,5 +1,7 @@
 //! `error`
 
+use crate::ErrorKind;
+
 /// `MigrationError`
 #[derive(Debug, Eq, PartialEq)]
 pub enum Error {
@@ -17,6 +19,24 @@ pub enum Error {
     TransactionFail(u16, String),
 }
 
+impl Error {
+    /// Get [`ErrorKind`] classified from the server error of [`Error::QueryFail`]
+    ///
+    /// The failed statement, which follows the server error as ` - statement`,
+    /// isn't classified.
+    /// For all other errors and messages, which aren't a known server error, it is `None`.
+    ///
+    #[must_use]
+    pub fn kind(&self) -> Option<ErrorKind> {
+        match self {
+            Error::QueryFail(msg) => {
+                ErrorKind::parse(msg.split_once(" - ").map_or(msg.as_str(), |(err, _)| err))
+            }
+            _ => None,
+        }
+    }
+}
+
 impl std::error::Error for Error {}
 
 impl std::fmt::Display for Error {
@@ -43,6 +63,8 @@ impl TryFrom<crate::Error> for Error {
             crate::Error::IoError(err) => Err(Error::DataMalformat(err.to_string())),
             #[cfg(feature = "migration")]
             crate::Error::MigrationError(err) => Err(err),
+            #[cfg(feature = "reqwest")]
+            crate::Error::ReqwestError(err) => Err(Error::QueryFail(err.to_string())),
             crate::Error::ResponseError(_v) => Err(Error::Internal("response error")),
             crate::Error::ResultError(msg) => Err(Error::QueryFail(msg)),
             crate::Error::SerdeError(_err) => Err(Error::Internal("json parse failed")),
@@ -1,12 +1,14 @@
 //! `Monitor` states for `Query<State>`
 #![cfg(feature = "monitor")]
 
-use crate::state::State;
+use crate::state::{State, StateResponse};
 
+pub use debug_vars::DebugVars;
 pub use endpoint::Endpoint;
 pub use nodes::Nodes;
 pub use readyz::Readyz;
 
+mod debug_vars;
 mod endpoint;
 mod nodes;
 mod readyz;
@@ -20,6 +22,9 @@ pub mod response;
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Monitor;
 impl State for Monitor {}
+impl StateResponse for Monitor {
+    type Response = response::Status;
+}
 
 #[cfg(test)]
 #[cfg(any(feature = "percent_encoding", feature = "url"))]
@@ -0,0 +1,46 @@
+use crate::state::{State, StateResponse};
+
+/// rqlite exposes detailed runtime counters with the Go `expvar` package,
+/// e.g. of the HTTP service, the store, the raft system, the queue, the cluster and the database.
+///
+/// See <https://rqlite.io/docs/guides/monitoring-rqlite/#expvar-support>
+///
+#[derive(Debug, Eq, PartialEq)]
+pub struct DebugVars;
+impl State for DebugVars {}
+impl StateResponse for DebugVars {
+    type Response = crate::monitor::response::DebugVars;
+}
+
+#[cfg(test)]
+#[cfg(any(feature = "percent_encoding", feature = "url"))]
+mod tests {
+    use std::{sync::OnceLock, time::Duration};
+
+    use crate::{Connection, Query};
+
+    const TEST_CONNECTION_URL: &str = "http://localhost:4001/";
+
+    static TEST_CONNECTION: OnceLock<Connection> = OnceLock::new();
+
+    fn test_connection() -> &'static Connection {
+        TEST_CONNECTION.get_or_init(|| {
+            #[cfg(feature = "url")]
+            let c = Connection::new(TEST_CONNECTION_URL).unwrap();
+            #[cfg(not(feature = "url"))]
+            let c = Connection::new(TEST_CONNECTION_URL);
+
+            c
+        })
+    }
+
+    #[test]
+    fn monitor_debug_vars_test() {
+        let mut q = Query::new(test_connection()).monitor().debug_vars();
+
+        assert_eq!(&q.create_path_with_query(), "/debug/vars");
+
+        q = q.set_timeout(Duration::from_secs(3).into());
+        assert_eq!(&q.create_path_with_query(), "/debug/vars?timeout=3s");
+    }
+}
@@ -1,9 +1,13 @@
-//! The supported monitor [`Endpoint`]s nodes, readyz, status
+//! The supported monitor [`Endpoint`]s debug vars, nodes, readyz, status
 
-/// The supported monitor `Endpoint`s nodes, readyz, status
+/// The supported monitor `Endpoint`s debug vars, nodes, readyz, status
 ///
-#[derive(Debug, Default, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 pub enum Endpoint {
+    /// `DebugVars` endpoint for runtime counters of `expvar`
+    ///
+    /// See <https://rqlite.io/docs/guides/monitoring-rqlite/#expvar-support>
+    DebugVars,
     /// `Nodes` endpoint for cluster node information
     ///
     /// See <https://rqlite.io/docs/guides/monitoring-rqlite/#nodes-api>
@@ -22,6 +26,7 @@ pub enum Endpoint {
 impl std::fmt::Display for Endpoint {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            Endpoint::DebugVars => f.write_str("/debug/vars"),
             Endpoint::Nodes => f.write_str("/nodes"),
             Endpoint::Readyz => f.write_str("/readyz"),
             Endpoint::Status => f.write_str("/status"),
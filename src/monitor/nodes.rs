@@ -1,4 +1,9 @@
-use crate::{state::State, Query};
+use std::ops::Deref;
+
+use crate::{
+    state::{State, StateResponse},
+    Connection, Query,
+};
 
 /// _Nodes_ return basic information for nodes in the cluster, as seen by the node
 /// receiving the nodes request. The receiving node will also check whether it can actually
@@ -14,8 +19,14 @@ use crate::{state::State, Query};
 #[derive(Debug, Eq, PartialEq)]
 pub struct Nodes;
 impl State for Nodes {}
+impl StateResponse for Nodes {
+    type Response = crate::monitor::response::Nodes;
+}
 
-impl Query<'_, Nodes> {
+impl<C> Query<'_, Nodes, C>
+where
+    C: Deref<Target = Connection>,
+{
     /// Enable nonvoters query param to check also read-only nodes
     ///
     /// See <https://rqlite.io/docs/guides/monitoring-rqlite/#nodes-api>
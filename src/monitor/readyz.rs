@@ -1,4 +1,9 @@
-use crate::{state::State, Query};
+use std::ops::Deref;
+
+use crate::{
+    state::{State, StateResponse},
+    Connection, Query,
+};
 
 /// rqlite nodes serve a _ready_ status [`monitor::Endpoint::Readyz`](crate::monitor::Endpoint::Readyz)
 /// if the node is ready to respond to database requests and cluster management operations.
@@ -11,8 +16,14 @@ use crate::{state::State, Query};
 #[derive(Debug, Eq, PartialEq)]
 pub struct Readyz;
 impl State for Readyz {}
+impl StateResponse for Readyz {
+    type Response = crate::monitor::response::Readyz;
+}
 
-impl Query<'_, Readyz> {
+impl<C> Query<'_, Readyz, C>
+where
+    C: Deref<Target = Connection>,
+{
     /// Enable noleader query param to check all nodes, regardless of Leader status
     ///
     /// See <https://rqlite.io/docs/guides/monitoring-rqlite/#readiness-checks>
@@ -1,11 +1,13 @@
 //! Responses of monitor endpoints
 
+pub use debug_vars::{Counters, DebugVars};
 pub use nodes::Nodes;
 pub use nodes_v2::NodesV2;
 pub use readyz::Readyz;
 pub use status::Status;
 
+pub mod debug_vars;
 mod nodes;
 mod nodes_v2;
 mod readyz;
-mod status;
+pub mod status;
@@ -0,0 +1,380 @@
+//! Typed counters of the [`DebugVars`] response
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Deserializer};
+
+use crate::Value;
+
+/// Data container for response of [`monitor::Endpoint::DebugVars`](crate::monitor::Endpoint::DebugVars)
+///
+/// The sections `cluster`, `db`, `http`, `queue` and `store` are available as typed structs
+/// with the counters of __rqlite__ as fields. A counter, which isn't available in the version
+/// of __rqlite__, is `None`. The other counters of a section are available as [`Counters`].
+/// A section, which isn't available or can't be deserialized, is `None`.
+///
+/// The `raft` section has no fixed counters and is available as [`Counters`] only.
+///
+/// Everything else, e.g. `cmdline` or `memstats` of the Go runtime, is available with
+/// [`DebugVars::raw()`] and [`DebugVars::get()`].
+///
+/// See also [`monitor::DebugVars`](crate::monitor::DebugVars) and
+/// <https://rqlite.io/docs/guides/monitoring-rqlite/#expvar-support>
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct DebugVars {
+    /// `cluster` counters
+    pub cluster: Option<Cluster>,
+    /// `db` counters
+    pub db: Option<Db>,
+    /// `http` counters
+    pub http: Option<Http>,
+    /// `queue` counters
+    pub queue: Option<Queue>,
+    /// `raft` counters
+    pub raft: Option<Counters>,
+    /// `store` counters
+    pub store: Option<Store>,
+    raw: Value,
+}
+
+impl DebugVars {
+    /// Get the raw value of the variable `key`
+    #[must_use]
+    #[inline]
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.raw.get(key)
+    }
+
+    /// Convert into the raw JSON document
+    #[must_use]
+    #[inline]
+    pub fn into_raw(self) -> Value {
+        self.raw
+    }
+
+    /// Raw JSON document
+    #[must_use]
+    #[inline]
+    pub fn raw(&self) -> &Value {
+        &self.raw
+    }
+}
+
+impl From<Value> for DebugVars {
+    fn from(raw: Value) -> Self {
+        Self {
+            cluster: section(&raw, "cluster"),
+            db: section(&raw, "db"),
+            http: section(&raw, "http"),
+            queue: section(&raw, "queue"),
+            raft: section(&raw, "raft"),
+            store: section(&raw, "store"),
+            raw,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for DebugVars {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Value::deserialize(deserializer).map(Self::from)
+    }
+}
+
+impl serde::Serialize for DebugVars {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.raw.serialize(serializer)
+    }
+}
+
+/// `cluster` counters of [`DebugVars`] for the requests between the nodes
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(default)]
+pub struct Cluster {
+    /// backup requests
+    pub num_backup_req: Option<i64>,
+    /// execute requests
+    pub num_execute_req: Option<i64>,
+    /// requests for the API address of a node
+    pub num_get_node_api: Option<i64>,
+    /// responses with the API address of a node
+    pub num_get_node_api_resp: Option<i64>,
+    /// join requests
+    pub num_join_req: Option<i64>,
+    /// load requests
+    pub num_load_req: Option<i64>,
+    /// notify requests
+    pub num_notify_req: Option<i64>,
+    /// query requests
+    pub num_query_req: Option<i64>,
+    /// remove node requests
+    pub num_remove_node_req: Option<i64>,
+    /// unified requests
+    pub num_request_req: Option<i64>,
+    /// other counters
+    #[serde(flatten)]
+    pub counters: Counters,
+}
+
+/// `db` counters of [`DebugVars`] for the _SQLite_ database
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(default)]
+pub struct Db {
+    /// failed WAL checkpoints
+    pub checkpoint_errors: Option<i64>,
+    /// WAL checkpoints
+    pub checkpoints: Option<i64>,
+    /// transactions of executions
+    pub execute_transactions: Option<i64>,
+    /// failed executions
+    pub execution_errors: Option<i64>,
+    /// executions
+    pub executions: Option<i64>,
+    /// failed queries
+    pub query_errors: Option<i64>,
+    /// transactions of queries
+    pub query_transactions: Option<i64>,
+    /// queries
+    pub queries: Option<i64>,
+    /// transactions of unified requests
+    pub request_transactions: Option<i64>,
+    /// unified requests
+    pub requests: Option<i64>,
+    /// other counters
+    #[serde(flatten)]
+    pub counters: Counters,
+}
+
+/// `http` counters of [`DebugVars`] for the requests of the HTTP API
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(default)]
+pub struct Http {
+    /// failed authentications
+    #[serde(rename = "authFail")]
+    pub auth_fail: Option<i64>,
+    /// successful authentications
+    #[serde(rename = "authOK")]
+    pub auth_ok: Option<i64>,
+    /// backup requests
+    pub backups: Option<i64>,
+    /// boot requests
+    pub boot: Option<i64>,
+    /// execute requests
+    pub executions: Option<i64>,
+    /// requests without a known leader
+    pub leader_not_found: Option<i64>,
+    /// load requests
+    pub loads: Option<i64>,
+    /// queries
+    pub queries: Option<i64>,
+    /// queued execute requests
+    pub queued_executions: Option<i64>,
+    /// failed queued execute requests
+    pub queued_executions_failed: Option<i64>,
+    /// execute requests forwarded to the leader
+    pub remote_executions: Option<i64>,
+    /// failed execute requests forwarded to the leader
+    pub remote_executions_failed: Option<i64>,
+    /// queries forwarded to the leader
+    pub remote_queries: Option<i64>,
+    /// failed queries forwarded to the leader
+    pub remote_queries_failed: Option<i64>,
+    /// unified requests forwarded to the leader
+    pub remote_requests: Option<i64>,
+    /// failed unified requests forwarded to the leader
+    pub remote_requests_failed: Option<i64>,
+    /// unified requests
+    pub requests: Option<i64>,
+    /// other counters
+    #[serde(flatten)]
+    pub counters: Counters,
+}
+
+/// `queue` counters of [`DebugVars`] for queued writes
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(default)]
+pub struct Queue {
+    /// explicit flushes of the queue
+    pub num_flush: Option<i64>,
+    /// flushes of the queue after its timeout
+    pub num_timeout: Option<i64>,
+    /// received statements
+    pub statements_rx: Option<i64>,
+    /// sent statements
+    pub statements_tx: Option<i64>,
+    /// other counters
+    #[serde(flatten)]
+    pub counters: Counters,
+}
+
+/// `store` counters of [`DebugVars`] for the raft store of the node
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(default)]
+pub struct Store {
+    /// missed heartbeats of other nodes
+    pub failed_heartbeat_observed: Option<i64>,
+    /// changes of the leader, which were dropped
+    pub leader_changes_dropped: Option<i64>,
+    /// changes of the leader
+    pub leader_changes_observed: Option<i64>,
+    /// nodes, which failed to be reaped
+    pub nodes_reaped_failed: Option<i64>,
+    /// reaped nodes
+    pub nodes_reaped_ok: Option<i64>,
+    /// backups
+    pub num_backups: Option<i64>,
+    /// boots with a _SQLite_ database file
+    pub num_boots: Option<i64>,
+    /// ignored join requests
+    pub num_ignored_joins: Option<i64>,
+    /// join requests
+    pub num_joins: Option<i64>,
+    /// loads
+    pub num_loads: Option<i64>,
+    /// recoveries of the cluster
+    pub num_recoveries: Option<i64>,
+    /// restores
+    pub num_restores: Option<i64>,
+    /// snapshots
+    pub num_snapshots: Option<i64>,
+    /// failed snapshots
+    pub num_snapshots_failed: Option<i64>,
+    /// other counters
+    #[serde(flatten)]
+    pub counters: Counters,
+}
+
+/// Integer counters of a section of [`DebugVars`] by name
+///
+/// The counters of a typed section like [`Http`] are the ones, which aren't fields.
+/// The names of the counters differ between the versions of __rqlite__.
+/// Values, which aren't integers, are skipped.
+///
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Counters(BTreeMap<String, i64>);
+
+impl Counters {
+    /// Get the counter `name`
+    #[must_use]
+    #[inline]
+    pub fn get(&self, name: &str) -> Option<i64> {
+        self.0.get(name).copied()
+    }
+
+    /// Check for no counters
+    #[must_use]
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterator of the counters sorted by name
+    pub fn iter(&self) -> impl Iterator<Item = (&str, i64)> {
+        self.0.iter().map(|(name, value)| (name.as_str(), *value))
+    }
+
+    /// Number of counters
+    #[must_use]
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<'de> Deserialize<'de> for Counters {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        BTreeMap::<String, Value>::deserialize(deserializer).map(|section| {
+            Self(
+                section
+                    .into_iter()
+                    .filter_map(|(name, value)| value.as_i64().map(|value| (name, value)))
+                    .collect(),
+            )
+        })
+    }
+}
+
+/// Deserialize the section `key` of `raw` or `None` if it fails
+fn section<T>(raw: &Value, key: &str) -> Option<T>
+where
+    T: for<'de> Deserialize<'de>,
+{
+    raw.get(key).and_then(|value| T::deserialize(value).ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DebugVars;
+
+    const DEBUG_VARS_JSON: &str = r#"{
+        "cmdline": ["rqlited", "-node-id", "1", "data"],
+        "memstats": {"Alloc": 1024},
+        "cluster": {"num_get_node_api": 3},
+        "db": {"execute_transactions": 2, "execution_errors": 0},
+        "http": {"executions": 7, "queries": 11, "authOK": 4, "custom": 1, "addr": "127.0.0.1:4001"},
+        "queue": {"statements_rx": 5},
+        "store": {"num_backups": 1, "leader_changes_observed": 1},
+        "raft": "unexpected"
+    }"#;
+
+    #[test]
+    fn deserialize_test() {
+        let debug_vars = serde_json::from_str::<DebugVars>(DEBUG_VARS_JSON).unwrap();
+
+        let http = debug_vars.http.as_ref().unwrap();
+        assert_eq!(http.executions, Some(7));
+        assert_eq!(http.queries, Some(11));
+        assert_eq!(http.auth_ok, Some(4));
+        assert_eq!(http.auth_fail, None);
+        assert_eq!(http.counters.get("custom"), Some(1));
+        assert_eq!(http.counters.get("queries"), None);
+        assert_eq!(http.counters.get("addr"), None);
+        assert_eq!(
+            http.counters.iter().collect::<Vec<_>>(),
+            vec![("custom", 1)]
+        );
+
+        let db = debug_vars.db.as_ref().unwrap();
+        assert_eq!(db.execute_transactions, Some(2));
+        assert_eq!(db.execution_errors, Some(0));
+        assert!(db.counters.is_empty());
+
+        assert_eq!(debug_vars.queue.as_ref().unwrap().statements_rx, Some(5));
+        let store = debug_vars.store.as_ref().unwrap();
+        assert_eq!(store.num_backups, Some(1));
+        assert_eq!(store.leader_changes_observed, Some(1));
+        assert_eq!(
+            debug_vars.cluster.as_ref().unwrap().num_get_node_api,
+            Some(3)
+        );
+        assert!(debug_vars.raft.is_none());
+        assert_eq!(debug_vars.get("memstats").unwrap()["Alloc"], 1024);
+
+        let json = serde_json::to_value(&debug_vars).unwrap();
+        assert_eq!(&json, debug_vars.raw());
+
+        let debug_vars = DebugVars::from(crate::Value::Null);
+        assert!(debug_vars.http.is_none());
+        assert!(debug_vars.into_raw().is_null());
+    }
+
+    #[test]
+    fn raft_test() {
+        let debug_vars = DebugVars::from(serde_json::json!({
+            "raft": {"applied_index": 12, "state": "Leader"}
+        }));
+
+        let raft = debug_vars.raft.unwrap();
+        assert_eq!(raft.get("applied_index"), Some(12));
+        assert_eq!(raft.len(), 1);
+    }
+}
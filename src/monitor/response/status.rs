@@ -1,11 +1,157 @@
+//! Typed sections of the [`Status`] response
+
+use std::ops::Deref;
+
+use serde::{Deserialize, Deserializer};
+
 use crate::Value;
 
 /// Data container for response of [`monitor::Endpoint::Status`](crate::monitor::Endpoint::Status)
 ///
-/// See also [`monitor::Monitor`](crate::monitor::Monitor)
+/// The sections of the JSON document are available as typed structs. All fields are optional
+/// and unknown fields are ignored, so that different versions of __rqlite__ are supported.
+/// A section, which can't be deserialized, is `None`.
 ///
-#[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
-pub struct Status(pub Value);
+/// Everything not modeled is available with [`Status::raw()`] and [`Status::get()`].
+///
+/// See also [`monitor::Monitor`](crate::monitor::Monitor) and
+/// <https://rqlite.io/docs/guides/monitoring-rqlite/#status-api>
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct Status {
+    /// `build` section
+    pub build: Option<Build>,
+    /// `http` section
+    pub http: Option<Http>,
+    /// `node` section
+    pub node: Option<Node>,
+    /// `os` section
+    pub os: Option<Os>,
+    /// `runtime` section
+    pub runtime: Option<Runtime>,
+    /// `store` section with `raft` and `sqlite3`
+    pub store: Option<Store>,
+    raw: Value,
+}
+
+impl Status {
+    /// Applied index of the raft log
+    #[must_use]
+    pub fn applied_index(&self) -> Option<u64> {
+        self.raft().and_then(|raft| raft.applied_index)
+    }
+
+    /// Size of the `SQLite` database in bytes
+    #[must_use]
+    pub fn db_size(&self) -> Option<u64> {
+        self.store
+            .as_ref()
+            .and_then(|store| store.sqlite3.as_ref())
+            .and_then(|sqlite3| sqlite3.db_size)
+    }
+
+    /// Get the raw value of the section `key`
+    #[must_use]
+    #[inline]
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.raw.get(key)
+    }
+
+    /// Convert into the raw JSON document
+    #[must_use]
+    #[inline]
+    pub fn into_raw(self) -> Value {
+        self.raw
+    }
+
+    /// Raft address of the leader
+    #[must_use]
+    pub fn leader_addr(&self) -> Option<&str> {
+        self.store
+            .as_ref()
+            .and_then(|store| store.leader.as_ref())
+            .and_then(|leader| leader.addr.as_deref())
+    }
+
+    /// Id of the node
+    #[must_use]
+    pub fn node_id(&self) -> Option<&str> {
+        self.store
+            .as_ref()
+            .and_then(|store| store.node_id.as_deref())
+    }
+
+    /// [`RaftState`] of the node
+    #[must_use]
+    pub fn raft_state(&self) -> Option<&RaftState> {
+        self.raft().and_then(|raft| raft.state.as_ref())
+    }
+
+    /// Raw JSON document
+    #[must_use]
+    #[inline]
+    pub fn raw(&self) -> &Value {
+        &self.raw
+    }
+
+    /// Version of the __rqlite__ build
+    #[must_use]
+    pub fn version(&self) -> Option<&str> {
+        self.build
+            .as_ref()
+            .and_then(|build| build.version.as_deref())
+    }
+
+    #[inline]
+    fn raft(&self) -> Option<&Raft> {
+        self.store.as_ref().and_then(|store| store.raft.as_ref())
+    }
+}
+
+/// Access to the raw JSON document like the former tuple struct `Status(pub Value)`
+///
+/// Deprecated, use [`Status::raw()`] or the typed sections instead.
+///
+impl Deref for Status {
+    type Target = Value;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.raw
+    }
+}
+
+impl From<Value> for Status {
+    fn from(raw: Value) -> Self {
+        Self {
+            build: section(&raw, "build"),
+            http: section(&raw, "http"),
+            node: section(&raw, "node"),
+            os: section(&raw, "os"),
+            runtime: section(&raw, "runtime"),
+            store: section(&raw, "store"),
+            raw,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Status {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Value::deserialize(deserializer).map(Self::from)
+    }
+}
+
+impl serde::Serialize for Status {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.raw.serialize(serializer)
+    }
+}
 
 #[cfg(feature = "ureq")]
 impl TryFrom<ureq::Response> for Status {
@@ -24,3 +170,296 @@ impl TryFrom<ureq::Response> for Status {
         response.into_json::<Self>().map_err(Self::Error::from)
     }
 }
+
+/// `build` section of [`Status`]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct Build {
+    /// git branch
+    pub branch: Option<String>,
+    /// time of the build
+    pub build_time: Option<String>,
+    /// git commit
+    pub commit: Option<String>,
+    /// Go compiler
+    pub compiler: Option<String>,
+    /// version of __rqlite__
+    pub version: Option<String>,
+}
+
+/// `http` section of [`Status`]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct Http {
+    /// `enabled` or `disabled` authentication
+    pub auth: Option<String>,
+    /// address the HTTP API is bound to
+    pub bind_addr: Option<String>,
+}
+
+/// `node` section of [`Status`]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct Node {
+    /// current time of the node
+    pub current_time: Option<String>,
+    /// start time of the node
+    pub start_time: Option<String>,
+    /// uptime of the node
+    pub uptime: Option<String>,
+}
+
+/// `os` section of [`Status`]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct Os {
+    /// path of the executable
+    pub executable: Option<String>,
+    /// hostname
+    pub hostname: Option<String>,
+    /// memory page size
+    #[serde(deserialize_with = "lenient_u64")]
+    pub page_size: Option<u64>,
+    /// process id
+    #[serde(deserialize_with = "lenient_u64")]
+    pub pid: Option<u64>,
+    /// parent process id
+    #[serde(deserialize_with = "lenient_u64")]
+    pub ppid: Option<u64>,
+}
+
+/// `runtime` section of [`Status`]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct Runtime {
+    /// `GOARCH`
+    #[serde(rename = "GOARCH")]
+    pub goarch: Option<String>,
+    /// `GOMAXPROCS`
+    #[serde(rename = "GOMAXPROCS", deserialize_with = "lenient_u64")]
+    pub gomaxprocs: Option<u64>,
+    /// `GOOS`
+    #[serde(rename = "GOOS")]
+    pub goos: Option<String>,
+    /// number of CPUs
+    #[serde(deserialize_with = "lenient_u64")]
+    pub num_cpu: Option<u64>,
+    /// number of goroutines
+    #[serde(deserialize_with = "lenient_u64")]
+    pub num_goroutine: Option<u64>,
+    /// Go version
+    pub version: Option<String>,
+}
+
+/// `store` section of [`Status`]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct Store {
+    /// raft address of the node
+    pub addr: Option<String>,
+    /// timeout for applying to the raft log
+    pub apply_timeout: Option<String>,
+    /// index of the raft log applied to the database
+    #[serde(deserialize_with = "lenient_u64")]
+    pub db_applied_index: Option<u64>,
+    /// data directory
+    pub dir: Option<String>,
+    /// size of the data directory in bytes
+    #[serde(deserialize_with = "lenient_u64")]
+    pub dir_size: Option<u64>,
+    /// index of the raft log applied to the FSM
+    #[serde(deserialize_with = "lenient_u64")]
+    pub fsm_index: Option<u64>,
+    /// leader of the cluster
+    #[serde(deserialize_with = "lenient")]
+    pub leader: Option<Leader>,
+    /// id of the node
+    pub node_id: Option<String>,
+    /// nodes of the cluster configuration
+    #[serde(deserialize_with = "lenient")]
+    pub nodes: Option<Vec<StoreNode>>,
+    /// `raft` section
+    #[serde(deserialize_with = "lenient")]
+    pub raft: Option<Raft>,
+    /// `sqlite3` section
+    #[serde(deserialize_with = "lenient")]
+    pub sqlite3: Option<Sqlite3>,
+}
+
+/// `leader` of [`Store`]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct Leader {
+    /// raft address of the leader
+    pub addr: Option<String>,
+    /// id of the leader
+    pub node_id: Option<String>,
+}
+
+/// Node of the cluster configuration in [`Store`]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct StoreNode {
+    /// raft address
+    pub addr: Option<String>,
+    /// id of the node
+    pub id: Option<String>,
+    /// `Voter` or `Nonvoter`
+    pub suffrage: Option<String>,
+}
+
+/// `raft` section of [`Store`]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct Raft {
+    /// index applied to the FSM
+    #[serde(deserialize_with = "lenient_u64")]
+    pub applied_index: Option<u64>,
+    /// index of the commit
+    #[serde(deserialize_with = "lenient_u64")]
+    pub commit_index: Option<u64>,
+    /// number of pending FSM operations
+    #[serde(deserialize_with = "lenient_u64")]
+    pub fsm_pending: Option<u64>,
+    /// index of the last log entry
+    #[serde(deserialize_with = "lenient_u64")]
+    pub last_log_index: Option<u64>,
+    /// term of the last log entry
+    #[serde(deserialize_with = "lenient_u64")]
+    pub last_log_term: Option<u64>,
+    /// index of the last snapshot
+    #[serde(deserialize_with = "lenient_u64")]
+    pub last_snapshot_index: Option<u64>,
+    /// term of the last snapshot
+    #[serde(deserialize_with = "lenient_u64")]
+    pub last_snapshot_term: Option<u64>,
+    /// number of peers
+    #[serde(deserialize_with = "lenient_u64")]
+    pub num_peers: Option<u64>,
+    /// [`RaftState`] of the node
+    #[serde(deserialize_with = "lenient")]
+    pub state: Option<RaftState>,
+    /// current term
+    #[serde(deserialize_with = "lenient_u64")]
+    pub term: Option<u64>,
+}
+
+/// State of the node in the raft cluster
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+pub enum RaftState {
+    /// Candidate for leader election
+    Candidate,
+    /// Follower of the leader
+    Follower,
+    /// Leader of the cluster
+    Leader,
+    /// Shut down
+    Shutdown,
+    /// Not known state
+    #[serde(other)]
+    Unknown,
+}
+
+/// `sqlite3` section of [`Store`]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct Sqlite3 {
+    /// compile options of `SQLite`
+    pub compile_options: Option<Vec<String>>,
+    /// size of the database in bytes
+    #[serde(deserialize_with = "lenient_u64")]
+    pub db_size: Option<u64>,
+    /// path of the database file
+    pub path: Option<String>,
+    /// version of `SQLite`
+    pub version: Option<String>,
+}
+
+/// Deserialize the section `key` of `raw` or `None` if it fails
+fn section<T>(raw: &Value, key: &str) -> Option<T>
+where
+    T: for<'de> Deserialize<'de>,
+{
+    raw.get(key).and_then(|value| T::deserialize(value).ok())
+}
+
+/// Deserialize `Option<T>`, which is `None` if it fails
+fn lenient<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Value::deserialize(deserializer).map(|value| T::deserialize(value).ok())
+}
+
+/// Deserialize `Option<u64>` of a number or a string with a number
+fn lenient_u64<'de, D>(deserializer: D) -> Result<Option<u64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Value::deserialize(deserializer).map(|value| match value {
+        Value::Number(n) => n.as_u64(),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RaftState, Status};
+
+    const STATUS_JSON: &str = r#"{
+        "build": {"branch": "master", "compiler": "gc", "version": "v8.15.0"},
+        "http": {"auth": "disabled", "bind_addr": "127.0.0.1:4001", "new_field": 1},
+        "node": {"start_time": "2024-01-01T00:00:00Z", "uptime": "1m0s"},
+        "os": {"hostname": "host", "pid": 42, "ppid": "1"},
+        "runtime": {"GOARCH": "amd64", "GOMAXPROCS": 8, "GOOS": "linux", "num_cpu": 8},
+        "store": {
+            "addr": "localhost:4002",
+            "db_applied_index": 5,
+            "leader": {"addr": "localhost:4002", "node_id": "1"},
+            "node_id": "1",
+            "nodes": [{"id": "1", "addr": "localhost:4002", "suffrage": "Voter"}],
+            "raft": {"applied_index": "5", "commit_index": 5, "state": "Leader", "term": "2"},
+            "sqlite3": {"db_size": 8192, "path": ":memory:", "version": "3.45.1"}
+        },
+        "cluster": {"api_addr": "localhost:4001"}
+    }"#;
+
+    #[test]
+    fn deserialize_test() {
+        let status = serde_json::from_str::<Status>(STATUS_JSON).unwrap();
+
+        assert_eq!(status.version(), Some("v8.15.0"));
+        assert_eq!(status.node_id(), Some("1"));
+        assert_eq!(status.leader_addr(), Some("localhost:4002"));
+        assert_eq!(status.raft_state(), Some(&RaftState::Leader));
+        assert_eq!(status.applied_index(), Some(5));
+        assert_eq!(status.db_size(), Some(8192));
+        assert_eq!(status.os.as_ref().unwrap().ppid, Some(1));
+        assert_eq!(status.runtime.as_ref().unwrap().gomaxprocs, Some(8));
+        assert_eq!(status.get("cluster").unwrap()["api_addr"], "localhost:4001");
+
+        let json = serde_json::to_value(&status).unwrap();
+        assert_eq!(&json, status.raw());
+        assert_eq!(&*status, status.raw());
+        assert_eq!(status["store"]["node_id"], "1");
+    }
+
+    #[test]
+    fn deserialize_lenient_test() {
+        let status = serde_json::from_str::<Status>(
+            r#"{"build": "unexpected", "store": {"raft": {"state": "Observer"}, "leader": []}}"#,
+        )
+        .unwrap();
+
+        assert!(status.build.is_none());
+        assert!(status.http.is_none());
+        assert_eq!(status.raft_state(), Some(&RaftState::Unknown));
+        assert!(status.leader_addr().is_none());
+
+        let status = Status::from(crate::Value::Null);
+        assert!(status.store.is_none());
+        assert!(status.into_raw().is_null());
+    }
+}
@@ -1,16 +1,31 @@
-//! The supported [`Endpoint`]s execute, nodes, query, readyz, request, status
+//! The supported [`Endpoint`]s backup, boot, execute, load, nodes, query, readyz, remove, request, status
 
-/// The supported `Endpoint`s execute, nodes, query, readyz, request, status
+/// The supported `Endpoint`s backup, boot, execute, load, nodes, query, readyz, remove, request, status
 ///
 /// [`monitor::Monitor`](crate::monitor::Monitor) endpoints require feature `monitor`.
 ///
-#[derive(Debug, Default, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 pub enum Endpoint {
+    /// [`Backup`](crate::backup::Backup) of the database
+    ///
+    /// See <https://rqlite.io/docs/guides/backup/>
+    Backup,
+
+    /// [`Boot`](crate::load::Boot) a single node with a _SQLite_ database file
+    ///
+    /// See <https://rqlite.io/docs/guides/backup/#booting-with-a-sqlite-database>
+    Boot,
+
     /// `Execute` modifications
     ///
     /// See <https://rqlite.io/docs/api/api/#writing-data>
     Execute,
 
+    /// [`Load`](crate::load::Load) a _SQLite_ database file or SQL dump
+    ///
+    /// See <https://rqlite.io/docs/guides/backup/#restoring-from-sqlite>
+    Load,
+
     /// [`Monitor`](crate::monitor::Monitor) endpoints
     ///
     /// Requires feature `monitor`.
@@ -25,19 +40,43 @@ pub enum Endpoint {
     #[default]
     Query,
 
+    /// [`Remove`](crate::cluster::Remove) a node from the cluster
+    ///
+    /// See <https://rqlite.io/docs/clustering/general-guidelines/#removing-or-replacing-a-node>
+    Remove,
+
     /// `Request` unified endpoint
     ///
     /// See <https://rqlite.io/docs/api/api/#unified-endpoint>
     Request,
 }
 
+impl Endpoint {
+    /// Check for `Endpoint`, which modifies the database or the cluster
+    #[inline]
+    pub(crate) fn is_write(self) -> bool {
+        matches!(
+            self,
+            Endpoint::Boot
+                | Endpoint::Execute
+                | Endpoint::Load
+                | Endpoint::Remove
+                | Endpoint::Request
+        )
+    }
+}
+
 impl std::fmt::Display for Endpoint {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            Endpoint::Backup => f.write_str("/db/backup"),
+            Endpoint::Boot => f.write_str("/boot"),
             Endpoint::Execute => f.write_str("/db/execute"),
+            Endpoint::Load => f.write_str("/db/load"),
             #[cfg(feature = "monitor")]
             Endpoint::Monitor(monitor) => monitor.fmt(f),
             Endpoint::Query => f.write_str("/db/query"),
+            Endpoint::Remove => f.write_str("/remove"),
             Endpoint::Request => f.write_str("/db/request"),
         }
     }
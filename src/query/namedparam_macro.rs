@@ -0,0 +1,33 @@
+//! `namedparam_macro`
+
+/// Handle different convertable `Value`s as named parameters
+///
+/// The parameters are used with `push_sql_named()` or `set_sql_named()` of [`Query`](crate::Query)
+/// for the placeholders `:name`, `@name` or `$name` of the statement.
+///
+/// See <https://rqlite.io/docs/api/api/#named-parameters>.
+///
+/// # Example
+///
+/// ```no_run
+/// use rqlite_client::namedparam;
+/// let params = namedparam! { name => "fido", age => 3 };
+///
+/// let con = rqlite_client::Connection::new("http://localhost:4001");
+/// #[cfg(feature = "url")]
+/// let con = con.unwrap();
+/// let query = con
+///     .execute()
+///     .push_sql_named("INSERT INTO tbl (name, age) VALUES(:name, :age)", params);
+/// ```
+///
+#[macro_export]
+macro_rules! namedparam {
+    ( $($k:ident => $v:expr),* $(,)? ) => {
+        {
+            let params: ::std::vec::Vec<(&'static str, $crate::Value)> =
+                ::std::vec![$((stringify!($k), $crate::ToSqlValue::to_sql_value(&$v))),*];
+            params
+        }
+    };
+}
@@ -6,62 +6,109 @@
 /// Trait `State` for `Query`
 pub trait State {}
 
+/// Trait `StateResponse` for the typed response of `Query` in a `State`
+///
+/// The [`Response`](crate::Response) of
+/// [`Query::request_run_typed()`](crate::Query::request_run_typed) is converted into
+/// [`StateResponse::Response`].
+///
+pub trait StateResponse: State {
+    /// Typed response of `Query` in this `State`
+    type Response: TryFrom<crate::Response>;
+}
+
 /// `LevelAuto`
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct LevelAuto;
 impl State for LevelAuto {}
+impl StateResponse for LevelAuto {
+    type Response = crate::response::Query;
+}
 
 /// `LevelAutoMulti`
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct LevelAutoMulti;
 impl State for LevelAutoMulti {}
+impl StateResponse for LevelAutoMulti {
+    type Response = crate::response::Query;
+}
 
 /// `LevelLinearizable`
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct LevelLinearizable;
 impl State for LevelLinearizable {}
+impl StateResponse for LevelLinearizable {
+    type Response = crate::response::Query;
+}
 
 /// `LevelLinearizableMulti`
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct LevelLinearizableMulti;
 impl State for LevelLinearizableMulti {}
+impl StateResponse for LevelLinearizableMulti {
+    type Response = crate::response::Query;
+}
 
 /// `LevelNone`
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct LevelNone;
 impl State for LevelNone {}
+impl StateResponse for LevelNone {
+    type Response = crate::response::Query;
+}
 
 /// `LevelNoneMulti`
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct LevelNoneMulti;
 impl State for LevelNoneMulti {}
+impl StateResponse for LevelNoneMulti {
+    type Response = crate::response::Query;
+}
 
 /// `NoLevel`
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct NoLevel;
 impl State for NoLevel {}
+impl StateResponse for NoLevel {
+    type Response = crate::response::Query;
+}
 
 /// `NoLevelMulti`
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct NoLevelMulti;
 impl State for NoLevelMulti {}
+impl StateResponse for NoLevelMulti {
+    type Response = crate::response::Query;
+}
 
 /// `LevelStrong`
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct LevelStrong;
 impl State for LevelStrong {}
+impl StateResponse for LevelStrong {
+    type Response = crate::response::Query;
+}
 
 /// `LevelStrongMulti`
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct LevelStrongMulti;
 impl State for LevelStrongMulti {}
+impl StateResponse for LevelStrongMulti {
+    type Response = crate::response::Query;
+}
 
 /// `LevelWeak`
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct LevelWeak;
 impl State for LevelWeak {}
+impl StateResponse for LevelWeak {
+    type Response = crate::response::Query;
+}
 
 /// `LevelWeakMulti`
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct LevelWeakMulti;
 impl State for LevelWeakMulti {}
+impl StateResponse for LevelWeakMulti {
+    type Response = crate::response::Query;
+}
@@ -0,0 +1,442 @@
+//! Prepared SQL [`Statement`]
+
+use std::{ops::Deref, sync::PoisonError};
+
+use crate::{log, tracing};
+use crate::{Connection, Error, ToSqlValue, Value};
+
+use super::{Query, State};
+
+/// Prepared SQL [`Statement`] for repeated requests with different parameters
+///
+/// A `Statement` is created with `Query::prepare()` and is bound to the [`Connection`](crate::Connection),
+/// the consistency level and all other settings of the [`Query`].
+/// The url of the request is created once and reused by every run.
+///
+/// The parameters of the SQL statement are parsed on creation, either positional `?`, `?NNN`
+/// or named `:name`, `@name`, `$name`. Before sending a request, the given parameters are
+/// validated against them.
+///
+/// ```no_run
+/// use rqlite_client::{varparam, Connection};
+///
+/// # #[cfg(feature = "url")]
+/// let con = Connection::new("http://localhost:4001").expect("url failed");
+/// # #[cfg(not(feature = "url"))]
+/// # let con = Connection::new("http://localhost:4001");
+///
+/// let mut statement = con
+///     .execute()
+///     .enable_transaction()
+///     .prepare("INSERT INTO tbl (name, age) VALUES (?, ?)")?;
+///
+/// statement.execute(&varparam!["fiona", 20])?;
+/// statement.execute_many(&[varparam!["declan", 30], varparam!["oona", 40]])?;
+///
+/// assert!(statement.execute(&varparam!["too few"]).is_err());
+/// # Ok::<(), rqlite_client::Error>(())
+/// ```
+///
+#[derive(Debug)]
+pub struct Statement<'a, T, C = &'a Connection>
+where
+    T: State,
+    C: Deref<Target = Connection>,
+{
+    parameters: Parameters,
+    query: Query<'a, T, C>,
+    sql: String,
+}
+
+impl<'a, T, C> Statement<'a, T, C>
+where
+    T: State,
+    C: Deref<Target = Connection>,
+{
+    /// Create `Statement` of `sql` for the settings of `query`
+    pub(crate) fn new(mut query: Query<'a, T, C>, sql: &str) -> Result<Self, Error> {
+        let parameters = Parameters::parse(sql)?;
+
+        log::debug!("statement: {sql} with parameters {parameters:?}");
+        tracing::debug!("statement: {sql} with parameters {parameters:?}");
+
+        // the statements are always sent in the body and don't change the url
+        query.sql.clear();
+        query.is_url_modified = false;
+        *query
+            .url_cache
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner) = None;
+
+        Ok(Self {
+            parameters,
+            query,
+            sql: sql.to_string(),
+        })
+    }
+
+    /// Names of the named parameters in the order of the first occurrence
+    ///
+    /// Empty for positional parameters.
+    ///
+    #[must_use]
+    #[inline]
+    pub fn parameter_names(&self) -> &[String] {
+        match &self.parameters {
+            Parameters::Named(names) => names,
+            Parameters::Positional(_) => &[],
+        }
+    }
+
+    /// Number of parameters
+    #[must_use]
+    #[inline]
+    pub fn parameter_count(&self) -> usize {
+        match &self.parameters {
+            Parameters::Named(names) => names.len(),
+            Parameters::Positional(count) => *count,
+        }
+    }
+
+    /// [`Query`] with the settings of `Statement`
+    #[must_use]
+    #[inline]
+    pub fn query(&self) -> &Query<'a, T, C> {
+        &self.query
+    }
+
+    /// SQL of `Statement`
+    #[must_use]
+    #[inline]
+    pub fn sql(&self) -> &str {
+        &self.sql
+    }
+
+    /// Statement with positional `params` after validation
+    fn positional<V>(&self, params: &[V]) -> Result<Value, Error>
+    where
+        V: ToSqlValue,
+    {
+        match self.parameters {
+            Parameters::Positional(count) if count == params.len() => {
+                let mut statement = Vec::with_capacity(params.len() + 1);
+                statement.push(Value::from(self.sql.as_str()));
+                statement.extend(params.iter().map(ToSqlValue::to_sql_value));
+                Ok(Value::Array(statement))
+            }
+            Parameters::Positional(count) => Err(Error::ResultError(format!(
+                "statement expects {count} parameters, got {}",
+                params.len()
+            ))),
+            Parameters::Named(_) => Err(Error::from("statement expects named parameters")),
+        }
+    }
+
+    /// Statement with named `params` after validation
+    fn named<I, K, V>(&self, params: I) -> Result<Value, Error>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: ToSqlValue,
+    {
+        let Parameters::Named(names) = &self.parameters else {
+            return Err(Error::from("statement expects positional parameters"));
+        };
+
+        let params = params
+            .into_iter()
+            .map(|(k, v)| (k.into(), v.to_sql_value()))
+            .collect::<serde_json::Map<String, Value>>();
+
+        if let Some(name) = names.iter().find(|name| !params.contains_key(*name)) {
+            return Err(Error::ResultError(format!("missing parameter {name}")));
+        }
+        if let Some(name) = params.keys().find(|key| !names.contains(key)) {
+            return Err(Error::ResultError(format!("unknown parameter {name}")));
+        }
+
+        Ok(Value::Array(vec![
+            Value::from(self.sql.as_str()),
+            Value::Object(params),
+        ]))
+    }
+}
+
+impl<T, C> Statement<'_, T, C>
+where
+    T: State,
+    C: Deref<Target = Connection>,
+{
+    /// Run `Statement` with positional `params`
+    ///
+    /// # Errors
+    ///
+    /// [`Error::ResultError`] if the number of `params` doesn't match, or
+    /// [Error](crate::Error) of [`Query::request_run()`]
+    ///
+    pub fn execute<V>(&mut self, params: &[V]) -> crate::response::Result
+    where
+        V: ToSqlValue,
+    {
+        let statement = self.positional(params)?;
+        self.run(vec![statement])
+    }
+
+    /// Run `Statement` for every row of positional parameters in a single request
+    ///
+    /// All `rows` are validated before the request is sent.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::ResultError`] if there are no `rows` or the number of parameters of a row
+    /// doesn't match, or [Error](crate::Error) of [`Query::request_run()`]
+    ///
+    pub fn execute_many<R, V>(&mut self, rows: &[R]) -> crate::response::Result
+    where
+        R: AsRef<[V]>,
+        V: ToSqlValue,
+    {
+        if rows.is_empty() {
+            return Err(Error::from("no rows for statement"));
+        }
+
+        let statements = rows
+            .iter()
+            .map(|params| self.positional(params.as_ref()))
+            .collect::<Result<Vec<_>, _>>()?;
+        self.run(statements)
+    }
+
+    /// Run `Statement` for every set of named parameters in a single request
+    ///
+    /// All `rows` are validated before the request is sent.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::ResultError`] if there are no `rows` or the names of the parameters of a row
+    /// don't match, or [Error](crate::Error) of [`Query::request_run()`]
+    ///
+    pub fn execute_many_named<R, I, K, V>(&mut self, rows: R) -> crate::response::Result
+    where
+        R: IntoIterator<Item = I>,
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: ToSqlValue,
+    {
+        let statements = rows
+            .into_iter()
+            .map(|params| self.named(params))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if statements.is_empty() {
+            return Err(Error::from("no rows for statement"));
+        }
+        self.run(statements)
+    }
+
+    /// Run `Statement` with named `params`
+    ///
+    /// # Errors
+    ///
+    /// [`Error::ResultError`] if the names of `params` don't match, or
+    /// [Error](crate::Error) of [`Query::request_run()`]
+    ///
+    pub fn execute_named<I, K, V>(&mut self, params: I) -> crate::response::Result
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: ToSqlValue,
+    {
+        let statement = self.named(params)?;
+        self.run(vec![statement])
+    }
+
+    /// Run the request with `statements`
+    fn run(&mut self, statements: Vec<Value>) -> crate::response::Result {
+        self.query.sql = statements;
+        log::trace!("sql: {}", self.query.sql_redacted());
+        tracing::trace!("sql: {}", self.query.sql_redacted());
+
+        let result = self.query.request_run();
+        self.query.sql.clear();
+        result
+    }
+}
+
+/// Parameters of a [`Statement`]
+#[derive(Debug, PartialEq)]
+enum Parameters {
+    /// Unique names of named parameters without prefix
+    Named(Vec<String>),
+    /// Number of positional parameters
+    Positional(usize),
+}
+
+impl Parameters {
+    /// Parse the parameters of `sql`
+    ///
+    /// Literals, quoted identifiers and comments are skipped.
+    ///
+    fn parse(sql: &str) -> Result<Self, Error> {
+        let mut names: Vec<String> = Vec::new();
+        let mut count = 0;
+        let mut chars = sql.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '\'' | '"' | '`' => skip_until(&mut chars, c),
+                '[' => skip_until(&mut chars, ']'),
+                '-' if chars.peek() == Some(&'-') => skip_until(&mut chars, '\n'),
+                '/' if chars.peek() == Some(&'*') => {
+                    let _ = chars.next();
+                    while let Some(c) = chars.next() {
+                        if c == '*' && chars.peek() == Some(&'/') {
+                            let _ = chars.next();
+                            break;
+                        }
+                    }
+                }
+                '?' => {
+                    let mut number = String::new();
+                    while let Some(digit) = chars.next_if(char::is_ascii_digit) {
+                        number.push(digit);
+                    }
+                    count = if number.is_empty() {
+                        count + 1
+                    } else {
+                        let index = number.parse::<usize>().map_err(|err| {
+                            Error::ResultError(format!("parameter ?{number}: {err}"))
+                        })?;
+                        count.max(index)
+                    };
+                }
+                ':' | '@' | '$' => {
+                    let mut name = String::new();
+                    while let Some(c) = chars.next_if(|c| c.is_alphanumeric() || *c == '_') {
+                        name.push(c);
+                    }
+                    if !name.is_empty() && !names.contains(&name) {
+                        names.push(name);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        match (count, names.is_empty()) {
+            (_, true) => Ok(Self::Positional(count)),
+            (0, false) => Ok(Self::Named(names)),
+            _ => Err(Error::from("mixed positional and named parameters")),
+        }
+    }
+}
+
+/// Skip `chars` up to and including `end`
+fn skip_until(chars: &mut std::iter::Peekable<std::str::Chars<'_>>, end: char) {
+    for c in chars.by_ref() {
+        if c == end {
+            break;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Parameters;
+    use crate::{namedparam, varparam, Connection, Value};
+
+    fn test_connection() -> Connection {
+        #[cfg(feature = "url")]
+        let c = Connection::new("http://localhost:4001/").unwrap();
+        #[cfg(not(feature = "url"))]
+        let c = Connection::new("http://localhost:4001/");
+
+        c
+    }
+
+    #[test]
+    fn validate_test() {
+        let c = test_connection();
+
+        let statement = c
+            .execute()
+            .prepare("INSERT INTO tbl (a, b) VALUES (?, ?)")
+            .unwrap();
+        assert_eq!(statement.parameter_count(), 2);
+        assert!(statement.parameter_names().is_empty());
+        assert_eq!(
+            statement.positional(&varparam![1, "b"]).unwrap(),
+            serde_json::json!(["INSERT INTO tbl (a, b) VALUES (?, ?)", 1, "b"])
+        );
+        assert_eq!(
+            statement.positional(&varparam![1]).unwrap_err().to_string(),
+            "statement expects 2 parameters, got 1"
+        );
+        assert!(statement.named(namedparam! { a => 1 }).is_err());
+
+        let statement = c
+            .query()
+            .set_strong()
+            .prepare("SELECT * FROM tbl WHERE a = :a AND b = :b")
+            .unwrap();
+        assert_eq!(statement.parameter_names(), ["a", "b"]);
+        assert_eq!(
+            statement.named(namedparam! { a => 1, b => "b" }).unwrap(),
+            serde_json::json!(["SELECT * FROM tbl WHERE a = :a AND b = :b", {"a": 1, "b": "b"}])
+        );
+        assert_eq!(
+            statement
+                .named(namedparam! { a => 1 })
+                .unwrap_err()
+                .to_string(),
+            "missing parameter b"
+        );
+        assert_eq!(
+            statement
+                .named(namedparam! { a => 1, b => 2, c => 3 })
+                .unwrap_err()
+                .to_string(),
+            "unknown parameter c"
+        );
+        assert!(statement.positional::<Value>(&[]).is_err());
+        assert!(statement.query().sql().is_empty());
+    }
+
+    #[test]
+    fn parse_positional_test() {
+        assert_eq!(
+            Parameters::parse("SELECT * FROM tbl").unwrap(),
+            Parameters::Positional(0)
+        );
+        assert_eq!(
+            Parameters::parse("INSERT INTO tbl (a, b) VALUES (?, ?)").unwrap(),
+            Parameters::Positional(2)
+        );
+        assert_eq!(
+            Parameters::parse("SELECT ?2, ?1, ?").unwrap(),
+            Parameters::Positional(3)
+        );
+        assert_eq!(
+            Parameters::parse("SELECT '?', \"?\", [a?] -- ?\n FROM tbl /* ? */ WHERE a = ?")
+                .unwrap(),
+            Parameters::Positional(1)
+        );
+    }
+
+    #[test]
+    fn parse_named_test() {
+        assert_eq!(
+            Parameters::parse("INSERT INTO tbl (a, b) VALUES (:a, @b) ON CONFLICT SET b = $b")
+                .unwrap(),
+            Parameters::Named(vec!["a".to_string(), "b".to_string()])
+        );
+        assert_eq!(
+            Parameters::parse("SELECT ':a' FROM tbl").unwrap(),
+            Parameters::Positional(0)
+        );
+        assert_eq!(
+            Parameters::parse("SELECT :a, ?").unwrap_err().to_string(),
+            "mixed positional and named parameters"
+        );
+    }
+}
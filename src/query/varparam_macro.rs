@@ -4,7 +4,8 @@
 ///
 /// To protect against [SQL injection](https://owasp.org/www-community/attacks/SQL_Injection) use
 /// parameterized statements.\
-/// It is possible to use ordered question mark statements or named parameters.
+/// It is possible to use ordered question mark statements or named parameters
+/// with the [`crate::namedparam!`]-macro.
 ///
 /// See <https://rqlite.io/docs/api/api/#parameterized-statements> and <https://rqlite.io/docs/api/api/#named-parameters>.
 ///
@@ -18,6 +19,6 @@
 #[macro_export]
 macro_rules! varparam {
     ( $($v:expr),* ) => {
-        [$(Into::<$crate::Value>::into($v)),*]
+        [$($crate::ToSqlValue::to_sql_value(&$v)),*]
     };
 }
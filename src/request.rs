@@ -2,20 +2,18 @@
 #![cfg(feature = "ureq")]
 
 use std::marker::PhantomData;
+use std::ops::Deref;
 use std::sync::OnceLock;
+use std::time::Duration;
 
-#[allow(clippy::module_name_repetitions)]
-pub use self::request_type::RequestType;
-use self::request_type::{Get, Post};
-use crate::response::Result;
-use crate::{log, tracing, Connection, Response};
+use crate::request_type::{Delete, Get, Post, RequestType};
+use crate::response::{Result, RowStream};
+use crate::{log, tracing, Auth, Connection, Response, Value};
 use crate::{
     query::{Query, State},
     Error, RequestBuilder,
 };
 
-#[allow(clippy::module_name_repetitions)]
-pub mod request_type;
 mod tls;
 
 /// Implemented [`Request`] handling utilizing _crate_ [`ureq`](https://crates.io/crates/ureq)
@@ -30,7 +28,7 @@ pub struct Request<T>
 where
     T: RequestType,
 {
-    agent: Option<ureq::Agent>,
+    connection: Option<Connection>,
     t: PhantomData<T>,
 }
 
@@ -42,26 +40,63 @@ where
     #[must_use]
     pub fn new() -> Self {
         Self {
-            agent: None,
+            connection: None,
             t: PhantomData,
         }
     }
 
     /// Create new `Request` for [`Connection`]
     ///
-    /// Build and use a new [`ureq::Agent`].
+    /// Use the [`ureq::Agent`] of the [`Connection`], which is shared by all its `Request`s.
     ///
     #[must_use]
     #[inline]
     pub fn from_connection(connection: &Connection) -> Self {
         let mut r = Request::<T>::new();
-        r.agent = Some(user_agent(Some(connection)));
+        r.connection = Some(connection.clone());
         r
     }
+
+    /// The [`ureq::Agent`] of the [`Connection`] or `None` for the default agent
+    ///
+    /// # Errors
+    ///
+    /// [`Error`] if the agent can't be created with the [`TlsConfig`](crate::TlsConfig)
+    ///
+    fn agent(&self) -> std::result::Result<Option<ureq::Agent>, Error> {
+        self.connection.as_ref().map(Connection::agent).transpose()
+    }
 }
 
+/// Build [`ureq::Agent`] with the settings of `connection`
+///
+/// # Errors
+///
+/// [`Error`] if the [`TlsConfig`](crate::TlsConfig) can't be applied
+///
+#[cfg_attr(
+    not(any(feature = "ureq_tls", feature = "ureq_webpki")),
+    allow(clippy::unnecessary_wraps)
+)]
+pub(crate) fn user_agent(connection: &Connection) -> std::result::Result<ureq::Agent, Error> {
+    let agent = agent_builder(Some(connection));
+
+    #[cfg(any(feature = "ureq_tls", feature = "ureq_webpki"))]
+    let agent = match connection.tls_config() {
+        Some(tls_config) => {
+            log::debug!("use {tls_config:?}");
+            tracing::debug!("use {tls_config:?}");
+            agent.tls_config(std::sync::Arc::new(tls_config.client_config()?))
+        }
+        None => agent,
+    };
+
+    Ok(agent.build())
+}
+
+/// [`ureq::AgentBuilder`] with the proxy of `connection`
 #[inline]
-pub(crate) fn user_agent(connection: Option<&Connection>) -> ureq::Agent {
+fn agent_builder(connection: Option<&Connection>) -> ureq::AgentBuilder {
     let agent = ureq::AgentBuilder::new().user_agent(
         DEFAULT_USER_AGENT.get_or_init(|| format!("rqlite_client/{}", crate::BUILD_TIME)),
     );
@@ -74,27 +109,41 @@ pub(crate) fn user_agent(connection: Option<&Connection>) -> ureq::Agent {
         })
         .or_else(Connection::detect_proxy);
 
-    let agent = if let Some(proxy) = &proxy {
-        log::debug!("try proxy {proxy}");
-        tracing::debug!("try proxy {proxy}");
+    if let Some(proxy) = &proxy {
+        log::debug!("try proxy {}", crate::connection::redact_userinfo(proxy));
+        tracing::debug!("try proxy {}", crate::connection::redact_userinfo(proxy));
         match ureq::Proxy::new(proxy) {
             Ok(ureq_proxy) => {
-                log::info!("use proxy {proxy}");
-                tracing::info!("use roxy {proxy}");
+                log::info!("use proxy {}", crate::connection::redact_userinfo(proxy));
+                tracing::info!("use proxy {}", crate::connection::redact_userinfo(proxy));
                 agent.proxy(ureq_proxy)
             }
             Err(err) => {
                 let _ = err;
-                log::warn!("fail proxy {proxy}: {err}");
-                tracing::warn!("fail proxy {proxy}: {err}");
+                log::warn!(
+                    "fail proxy {}: {err}",
+                    crate::connection::redact_userinfo(proxy)
+                );
+                tracing::warn!(
+                    "fail proxy {}: {err}",
+                    crate::connection::redact_userinfo(proxy)
+                );
                 agent
             }
         }
     } else {
         agent
-    };
+    }
+}
 
-    agent.build()
+/// Set HTTP header of [`Auth`] for `request`
+#[inline]
+fn auth_header(request: ureq::Request, auth: Option<&Auth>) -> ureq::Request {
+    if let Some(auth) = auth {
+        request.set(auth.header_name(), &auth.header_value())
+    } else {
+        request
+    }
 }
 
 /// default HTTP User-Agent header
@@ -103,20 +152,39 @@ static DEFAULT_USER_AGENT: OnceLock<String> = OnceLock::new();
 /// request agent singleton
 static UREQ_AGENT: OnceLock<ureq::Agent> = OnceLock::new();
 
-impl Request<Get> {
-    fn request<T: State>(agent: Option<&ureq::Agent>, query: &Query<T>) -> Result {
-        log::debug!("[GET] {query}: {:?}", query.sql());
-        tracing::debug!("[GET] {query}: {:?}", query.sql());
+/// Default [`ureq::Agent`] without [`Connection`]
+#[inline]
+fn default_agent() -> &'static ureq::Agent {
+    UREQ_AGENT.get_or_init(|| agent_builder(None).build())
+}
 
-        let agent = if let Some(agent) = agent {
+impl Request<Delete> {
+    /// Run `Request` for `Query` with the JSON `body` and get the text of the response body
+    ///
+    /// See [`Query::request_remove()`](crate::Query::request_remove)
+    ///
+    /// # Errors
+    ///
+    /// [`Error`] on failing request or [`Error::HttpError`] with the text of the response body
+    /// on HTTP status not 2xx
+    ///
+    pub fn run_delete<T: State, C: Deref<Target = Connection>>(
+        &self,
+        query: &Query<'_, T, C>,
+        body: &Value,
+    ) -> std::result::Result<String, Error> {
+        log::debug!("[DELETE] {}", query.url_redacted());
+        tracing::debug!("[DELETE] {}", query.url_redacted());
+
+        let agent = self.agent()?;
+        let agent = agent.as_ref().unwrap_or_else(|| default_agent());
+
+        let r = auth_header(
             agent
-        } else {
-            UREQ_AGENT.get_or_init(|| user_agent(None))
-        };
-
-        let r = agent
-            .get(&query.to_string())
-            .set("Content-Type", "application/json");
+                .delete(&query.url())
+                .set("Content-Type", "application/json"),
+            query.connection_ref().auth(),
+        );
 
         let r = if let Some(timeout) = query.timeout_request() {
             r.timeout(*timeout)
@@ -124,26 +192,217 @@ impl Request<Get> {
             r
         };
 
-        let r = r.call().map_err(Error::from)?;
-
-        Response::try_from(r)
+        match r.send_json(body) {
+            Ok(r) => r.into_string().map_err(Error::from),
+            Err(ureq::Error::Status(status, r)) => {
+                let status_text = r.status_text().to_string();
+                let msg = r
+                    .into_string()
+                    .ok()
+                    .map(|msg| msg.trim().to_string())
+                    .filter(|msg| !msg.is_empty())
+                    .unwrap_or(status_text);
+                Err(Error::HttpError(status, msg))
+            }
+            Err(err) => Err(Error::from(err)),
+        }
     }
 }
 
-impl Request<Post> {
-    fn request<T: State>(agent: Option<&ureq::Agent>, query: &Query<T>) -> Result {
-        log::debug!("[POST] {query}: {:?}", query.sql());
-        tracing::debug!("[POST] {query}: {:?}", query.sql());
+impl Request<Get> {
+    /// Run `Request` for `Query`, see [`RequestBuilder::run()`]
+    pub(crate) fn run_query<T: State, C: Deref<Target = Connection>>(
+        &self,
+        query: &Query<'_, T, C>,
+    ) -> Result {
+        Self::request(self.agent()?.as_ref(), query)
+    }
 
-        let agent = if let Some(agent) = agent {
-            agent
+    fn request<T: State, C: Deref<Target = Connection>>(
+        agent: Option<&ureq::Agent>,
+        query: &Query<'_, T, C>,
+    ) -> Result {
+        log::debug!("[GET] {}: {}", query.url_redacted(), query.sql_redacted());
+        tracing::debug!("[GET] {}: {}", query.url_redacted(), query.sql_redacted());
+
+        Self::call(
+            agent,
+            &query.url(),
+            query.timeout_request().copied(),
+            query.connection_ref().auth(),
+        )
+    }
+
+    /// Run `Request` for `Query` and stream the rows of the response
+    ///
+    /// See [`Query::request_stream()`]
+    ///
+    /// # Errors
+    ///
+    /// [`Error`] on failing request or HTTP status not 2xx
+    ///
+    pub fn run_stream<T: State, C: Deref<Target = Connection>>(
+        &self,
+        query: &Query<'_, T, C>,
+    ) -> std::result::Result<RowStream, Error> {
+        log::debug!(
+            "[GET] stream {}: {}",
+            query.url_redacted(),
+            query.sql_redacted()
+        );
+        tracing::debug!(
+            "[GET] stream {}: {}",
+            query.url_redacted(),
+            query.sql_redacted()
+        );
+
+        let r = Self::send(
+            self.agent()?.as_ref(),
+            &query.url(),
+            query.timeout_request().copied(),
+            query.connection_ref().auth(),
+        )?;
+
+        RowStream::try_from(r)
+    }
+
+    /// Run `Request` for `Query` and get a reader of the raw response body
+    ///
+    /// See [`Query::request_backup()`](crate::Query::request_backup)
+    ///
+    /// # Errors
+    ///
+    /// [`Error`] on failing request or HTTP status not 2xx
+    ///
+    pub fn run_read<T: State, C: Deref<Target = Connection>>(
+        &self,
+        query: &Query<'_, T, C>,
+    ) -> std::result::Result<Box<dyn std::io::Read + Send>, Error> {
+        log::debug!("[GET] read {}", query.url_redacted());
+        tracing::debug!("[GET] read {}", query.url_redacted());
+
+        let r = Self::send(
+            self.agent()?.as_ref(),
+            &query.url(),
+            query.timeout_request().copied(),
+            query.connection_ref().auth(),
+        )?;
+
+        Ok(r.into_reader())
+    }
+
+    fn call(
+        agent: Option<&ureq::Agent>,
+        url: &str,
+        timeout: Option<Duration>,
+        auth: Option<&Auth>,
+    ) -> Result {
+        Response::try_from(Self::send(agent, url, timeout, auth)?)
+    }
+
+    fn send(
+        agent: Option<&ureq::Agent>,
+        url: &str,
+        timeout: Option<Duration>,
+        auth: Option<&Auth>,
+    ) -> std::result::Result<ureq::Response, Error> {
+        let agent = agent.unwrap_or_else(|| default_agent());
+
+        let r = auth_header(agent.get(url).set("Content-Type", "application/json"), auth);
+
+        let r = if let Some(timeout) = timeout {
+            r.timeout(timeout)
         } else {
-            UREQ_AGENT.get_or_init(|| user_agent(None))
+            r
         };
 
-        let r = agent
-            .post(&query.to_string())
-            .set("Content-Type", "application/json");
+        r.call().map_err(Error::from)
+    }
+}
+
+impl Request<Post> {
+    /// Run `Request` for `Query`, see [`RequestBuilder::run()`]
+    pub(crate) fn run_query<T: State, C: Deref<Target = Connection>>(
+        &self,
+        query: &Query<'_, T, C>,
+    ) -> Result {
+        Self::request(self.agent()?.as_ref(), query)
+    }
+
+    fn request<T: State, C: Deref<Target = Connection>>(
+        agent: Option<&ureq::Agent>,
+        query: &Query<'_, T, C>,
+    ) -> Result {
+        log::debug!("[POST] {}: {}", query.url_redacted(), query.sql_redacted());
+        tracing::debug!("[POST] {}: {}", query.url_redacted(), query.sql_redacted());
+
+        Self::call(
+            agent,
+            &query.url(),
+            query.timeout_request().copied(),
+            query.connection_ref().auth(),
+            query.sql(),
+        )
+    }
+
+    /// Run `Request` for `Query` and stream the rows of the response
+    ///
+    /// See [`Query::request_stream()`]
+    ///
+    /// # Errors
+    ///
+    /// [`Error`] on failing request or HTTP status not 2xx
+    ///
+    pub fn run_stream<T: State, C: Deref<Target = Connection>>(
+        &self,
+        query: &Query<'_, T, C>,
+    ) -> std::result::Result<RowStream, Error> {
+        log::debug!(
+            "[POST] stream {}: {}",
+            query.url_redacted(),
+            query.sql_redacted()
+        );
+        tracing::debug!(
+            "[POST] stream {}: {}",
+            query.url_redacted(),
+            query.sql_redacted()
+        );
+
+        let r = Self::send(
+            self.agent()?.as_ref(),
+            &query.url(),
+            query.timeout_request().copied(),
+            query.connection_ref().auth(),
+            query.sql(),
+        )?;
+
+        RowStream::try_from(r)
+    }
+
+    /// Run `Request` for `Query` with the body from `reader` and get a reader of the raw response body
+    ///
+    /// See [`Query::request_load()`](crate::Query::request_load)
+    ///
+    /// # Errors
+    ///
+    /// [`Error`] on failing request or HTTP status not 2xx
+    ///
+    pub fn run_upload<T: State, C: Deref<Target = Connection>, R: std::io::Read>(
+        &self,
+        query: &Query<'_, T, C>,
+        content_type: &str,
+        reader: R,
+    ) -> std::result::Result<Box<dyn std::io::Read + Send>, Error> {
+        log::debug!("[POST] upload {}: {content_type}", query.url_redacted());
+        tracing::debug!("[POST] upload {}: {content_type}", query.url_redacted());
+
+        let agent = self.agent()?;
+        let agent = agent.as_ref().unwrap_or_else(|| default_agent());
+
+        let r = auth_header(
+            agent.post(&query.url()).set("Content-Type", content_type),
+            query.connection_ref().auth(),
+        );
 
         let r = if let Some(timeout) = query.timeout_request() {
             r.timeout(*timeout)
@@ -151,9 +410,40 @@ impl Request<Post> {
             r
         };
 
-        let r = r.send_json(query.sql()).map_err(Error::from)?;
+        Ok(r.send(reader).map_err(Error::from)?.into_reader())
+    }
+
+    fn call(
+        agent: Option<&ureq::Agent>,
+        url: &str,
+        timeout: Option<Duration>,
+        auth: Option<&Auth>,
+        sql: &Vec<Value>,
+    ) -> Result {
+        Response::try_from(Self::send(agent, url, timeout, auth, sql)?)
+    }
+
+    fn send(
+        agent: Option<&ureq::Agent>,
+        url: &str,
+        timeout: Option<Duration>,
+        auth: Option<&Auth>,
+        sql: &Vec<Value>,
+    ) -> std::result::Result<ureq::Response, Error> {
+        let agent = agent.unwrap_or_else(|| default_agent());
+
+        let r = auth_header(
+            agent.post(url).set("Content-Type", "application/json"),
+            auth,
+        );
+
+        let r = if let Some(timeout) = timeout {
+            r.timeout(timeout)
+        } else {
+            r
+        };
 
-        Response::try_from(r)
+        r.send_json(sql).map_err(Error::from)
     }
 }
 
@@ -184,6 +474,12 @@ where
     }
 }
 
+impl From<Delete> for Request<Delete> {
+    fn from(_value: Delete) -> Self {
+        Request::<Delete>::new()
+    }
+}
+
 impl From<Get> for Request<Get> {
     fn from(_value: Get) -> Self {
         Request::<Get>::new()
@@ -202,7 +498,7 @@ where
 {
     #[inline]
     fn run(&self, query: &Query<S>) -> Result {
-        Self::request(self.agent.as_ref(), query)
+        self.run_query(query)
     }
 }
 
@@ -212,10 +508,97 @@ where
 {
     #[inline]
     fn run(&self, query: &Query<S>) -> Result {
-        Self::request(self.agent.as_ref(), query)
+        self.run_query(query)
+    }
+}
+
+/// Runs the blocking `ureq` request on the `tokio` blocking thread pool
+///
+/// Requires feature `tokio`.
+///
+#[cfg(feature = "tokio")]
+impl<S> crate::AsyncRequestBuilder<S> for Request<Get>
+where
+    S: State,
+{
+    #[inline]
+    fn run_async<C>(&self, query: &Query<'_, S, C>) -> crate::ResponseFuture
+    where
+        C: Deref<Target = Connection>,
+    {
+        self.run_query_async(query)
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl Request<Get> {
+    /// Run asynchronous `Request` for `Query`, see [`crate::AsyncRequestBuilder::run_async()`]
+    pub(crate) fn run_query_async<T: State, C: Deref<Target = Connection>>(
+        &self,
+        query: &Query<'_, T, C>,
+    ) -> crate::ResponseFuture {
+        log::debug!("[GET] {}: {}", query.url_redacted(), query.sql_redacted());
+        tracing::debug!("[GET] {}: {}", query.url_redacted(), query.sql_redacted());
+
+        let agent = self.agent();
+        let url = query.url();
+        let timeout = query.timeout_request().copied();
+        let auth = query.connection_ref().auth().cloned();
+
+        spawn_blocking(move || Self::call(agent?.as_ref(), &url, timeout, auth.as_ref()))
+    }
+}
+
+/// Runs the blocking `ureq` request on the `tokio` blocking thread pool
+///
+/// Requires feature `tokio`.
+///
+#[cfg(feature = "tokio")]
+impl<S> crate::AsyncRequestBuilder<S> for Request<Post>
+where
+    S: State,
+{
+    #[inline]
+    fn run_async<C>(&self, query: &Query<'_, S, C>) -> crate::ResponseFuture
+    where
+        C: Deref<Target = Connection>,
+    {
+        self.run_query_async(query)
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl Request<Post> {
+    /// Run asynchronous `Request` for `Query`, see [`crate::AsyncRequestBuilder::run_async()`]
+    pub(crate) fn run_query_async<T: State, C: Deref<Target = Connection>>(
+        &self,
+        query: &Query<'_, T, C>,
+    ) -> crate::ResponseFuture {
+        log::debug!("[POST] {}: {}", query.url_redacted(), query.sql_redacted());
+        tracing::debug!("[POST] {}: {}", query.url_redacted(), query.sql_redacted());
+
+        let agent = self.agent();
+        let url = query.url();
+        let timeout = query.timeout_request().copied();
+        let auth = query.connection_ref().auth().cloned();
+        let sql = query.sql().clone();
+
+        spawn_blocking(move || Self::call(agent?.as_ref(), &url, timeout, auth.as_ref(), &sql))
     }
 }
 
+#[cfg(feature = "tokio")]
+fn spawn_blocking<F>(f: F) -> crate::ResponseFuture
+where
+    F: FnOnce() -> Result + Send + 'static,
+{
+    Box::pin(async move {
+        tokio::task::spawn_blocking(f)
+            .await
+            .map_err(|err| Error::from(std::io::Error::new(std::io::ErrorKind::Other, err)))?
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
@@ -226,15 +609,15 @@ mod tests {
     #[test]
     fn request_get_test() {
         let request = Request::<Get>::new();
-        assert!(request.agent.is_none());
-        assert!(Request::from(Get).agent.is_none());
+        assert!(request.connection.is_none());
+        assert!(Request::from(Get).connection.is_none());
     }
 
     #[test]
     fn request_post_test() {
         let request = Request::<Post>::new();
-        assert!(request.agent.is_none());
-        assert!(Request::from(Post).agent.is_none());
+        assert!(request.connection.is_none());
+        assert!(Request::from(Post).connection.is_none());
     }
 
     #[test]
@@ -243,7 +626,7 @@ mod tests {
         #[cfg(feature = "url")]
         let c = c.unwrap();
 
-        assert!(Request::<Get>::from(&c).agent.is_some());
-        assert!(Request::<Post>::from(c).agent.is_some());
+        assert!(Request::<Get>::from(&c).connection.is_some());
+        assert!(Request::<Post>::from(c).connection.is_some());
     }
 }
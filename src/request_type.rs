@@ -0,0 +1,20 @@
+//! HTTP [`RequestType`]s for [`Delete`], [`Get`] and [`Post`]
+#![cfg(any(feature = "reqwest", feature = "ureq"))]
+
+/// Trait [`RequestType`] of `Request` (feature `ureq`) and `ReqwestRequest` (feature `reqwest`)
+pub trait RequestType {}
+
+/// HTTP `Delete` `RequestType` of `Request` (feature `ureq`) and `ReqwestRequest` (feature `reqwest`)
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Delete;
+impl RequestType for Delete {}
+
+/// HTTP `Get` `RequestType` of `Request` (feature `ureq`) and `ReqwestRequest` (feature `reqwest`)
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Get;
+impl RequestType for Get {}
+
+/// HTTP `Post` `RequestType` of `Request` (feature `ureq`) and `ReqwestRequest` (feature `reqwest`)
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Post;
+impl RequestType for Post {}
@@ -0,0 +1,722 @@
+//! Implemented [`ReqwestRequest`] handling utilizing _crate_ [`reqwest`](https://crates.io/crates/reqwest)
+#![cfg(feature = "reqwest")]
+
+use std::marker::PhantomData;
+use std::ops::Deref;
+use std::sync::OnceLock;
+
+use crate::connection::{shared_agent, SharedAgent};
+
+use crate::request_type::{Delete, Get, Post, RequestType};
+use crate::response::{Result, RowStream};
+use crate::{log, tracing, Auth, Connection, Response, Value};
+use crate::{
+    query::{Query, State},
+    AsyncRequestBuilder, Error, RequestBuilder, ResponseFuture,
+};
+
+/// Implemented [`ReqwestRequest`] handling utilizing _crate_ [`reqwest`](https://crates.io/crates/reqwest)
+///
+/// Requires enabled feature `reqwest`.
+///
+/// It implements the blocking [`RequestBuilder`] with [`reqwest::blocking::Client`]
+/// and the [`AsyncRequestBuilder`] with [`reqwest::Client`].
+///
+/// The blocking [`RequestBuilder::run()`] must not be called from inside of an asynchronous
+/// runtime. Use [`AsyncRequestBuilder::run_async()`] there.
+///
+/// If feature `ureq` is disabled, [`Query::request_run()`] uses `ReqwestRequest`.\
+/// [`Query::request_run_async()`] always prefers `ReqwestRequest`, if feature `reqwest` is enabled.
+///
+#[derive(Clone, Debug)]
+pub struct ReqwestRequest<T>
+where
+    T: RequestType,
+{
+    client: SharedAgent<reqwest::Client>,
+    client_blocking: SharedAgent<reqwest::blocking::Client>,
+    client_blocking_shared: Option<SharedAgent<reqwest::blocking::Client>>,
+    proxy: Option<String>,
+    t: PhantomData<T>,
+    #[cfg(any(feature = "reqwest_tls", feature = "reqwest_webpki"))]
+    tls_config: Option<crate::TlsConfig>,
+}
+
+impl<T> ReqwestRequest<T>
+where
+    T: RequestType,
+{
+    /// Create new `ReqwestRequest`
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            client: SharedAgent::default(),
+            client_blocking: SharedAgent::default(),
+            client_blocking_shared: None,
+            proxy: None,
+            t: PhantomData,
+            #[cfg(any(feature = "reqwest_tls", feature = "reqwest_webpki"))]
+            tls_config: None,
+        }
+    }
+
+    /// Create new `ReqwestRequest` for [`Connection`]
+    ///
+    /// Use the [`reqwest::Client`] and the [`reqwest::blocking::Client`] of the [`Connection`],
+    /// which are shared by all its `ReqwestRequest`s.
+    ///
+    /// The connection pool of the asynchronous [`reqwest::Client`] is bound to the runtime, which
+    /// opened the connections. For several runtimes use a separate [`Connection`] per runtime.
+    ///
+    #[must_use]
+    #[inline]
+    pub fn from_connection(connection: &Connection) -> Self {
+        let mut r = ReqwestRequest::<T>::new();
+        r.client_blocking_shared = Some(connection.reqwest_client_blocking().clone());
+        r.client = connection.reqwest_client().clone();
+        r.proxy = connection.proxy().map(String::from);
+        #[cfg(any(feature = "reqwest_tls", feature = "reqwest_webpki"))]
+        {
+            r.tls_config = connection.tls_config().cloned();
+        }
+        r
+    }
+
+    /// The asynchronous [`reqwest::Client`] of this `ReqwestRequest`
+    ///
+    /// The client is created on first usage and shared with the [`Connection`],
+    /// if there is one.
+    ///
+    fn client(&self) -> std::result::Result<reqwest::Client, Error> {
+        shared_agent(&self.client, || {
+            let builder = Self::proxy(
+                self.proxy.as_deref(),
+                reqwest::Client::builder().user_agent(default_user_agent()),
+                reqwest::ClientBuilder::proxy,
+            );
+            #[cfg(any(feature = "reqwest_tls", feature = "reqwest_webpki"))]
+            let builder = Self::tls(
+                self.tls_config.as_ref(),
+                builder,
+                reqwest::ClientBuilder::use_preconfigured_tls,
+            )?;
+
+            builder.build().map_err(Error::from)
+        })
+    }
+
+    /// The [`reqwest::blocking::Client`] of this `ReqwestRequest`
+    ///
+    /// The client of the [`Connection`] is used, if there is one.
+    /// Otherwise without proxy a shared default client is used.
+    ///
+    fn client_blocking(&self) -> std::result::Result<reqwest::blocking::Client, Error> {
+        if let Some(shared) = &self.client_blocking_shared {
+            return shared_agent(shared, || {
+                let builder = client_blocking_builder(self.proxy.as_deref());
+                #[cfg(any(feature = "reqwest_tls", feature = "reqwest_webpki"))]
+                let builder = Self::tls(
+                    self.tls_config.as_ref(),
+                    builder,
+                    reqwest::blocking::ClientBuilder::use_preconfigured_tls,
+                )?;
+
+                builder.build().map_err(Error::from)
+            });
+        }
+
+        if self.proxy.is_none() {
+            if let Some(client) = REQWEST_CLIENT_BLOCKING.get() {
+                return Ok(client.clone());
+            }
+            let client = client_blocking(None)?;
+            return Ok(REQWEST_CLIENT_BLOCKING.get_or_init(|| client).clone());
+        }
+
+        shared_agent(&self.client_blocking, || {
+            client_blocking(self.proxy.as_deref())
+        })
+    }
+
+    /// Apply the [`TlsConfig`](crate::TlsConfig) with `apply` to some client `builder`
+    ///
+    /// # Errors
+    ///
+    /// [`Error`] if the `TlsConfig` can't be applied
+    ///
+    #[cfg(any(feature = "reqwest_tls", feature = "reqwest_webpki"))]
+    fn tls<B>(
+        tls_config: Option<&crate::TlsConfig>,
+        builder: B,
+        apply: fn(B, rustls::ClientConfig) -> B,
+    ) -> std::result::Result<B, Error> {
+        match tls_config {
+            Some(tls_config) => {
+                log::debug!("use {tls_config:?}");
+                tracing::debug!("use {tls_config:?}");
+                Ok(apply(builder, tls_config.client_config()?))
+            }
+            None => Ok(builder),
+        }
+    }
+
+    /// Apply `proxy` with `apply` to some client `builder`
+    fn proxy<B>(proxy: Option<&str>, builder: B, apply: fn(B, reqwest::Proxy) -> B) -> B {
+        let proxy = proxy.map(String::from).or_else(Connection::detect_proxy);
+
+        if let Some(proxy) = &proxy {
+            log::debug!("try proxy {}", crate::connection::redact_userinfo(proxy));
+            tracing::debug!("try proxy {}", crate::connection::redact_userinfo(proxy));
+            match reqwest::Proxy::all(proxy) {
+                Ok(reqwest_proxy) => {
+                    log::info!("use proxy {}", crate::connection::redact_userinfo(proxy));
+                    tracing::info!("use proxy {}", crate::connection::redact_userinfo(proxy));
+                    apply(builder, reqwest_proxy)
+                }
+                Err(err) => {
+                    let _ = err;
+                    log::warn!(
+                        "fail proxy {}: {err}",
+                        crate::connection::redact_userinfo(proxy)
+                    );
+                    tracing::warn!(
+                        "fail proxy {}: {err}",
+                        crate::connection::redact_userinfo(proxy)
+                    );
+                    builder
+                }
+            }
+        } else {
+            builder
+        }
+    }
+}
+
+/// Apply HTTP header of [`Auth`] with `apply` to some request `builder`
+///
+/// The header replaces the Basic authentication of the url userinfo.
+///
+fn auth_header<B>(
+    builder: B,
+    auth: Option<&Auth>,
+    apply: fn(B, reqwest::header::HeaderMap) -> B,
+) -> std::result::Result<B, Error> {
+    let Some(auth) = auth else {
+        return Ok(builder);
+    };
+
+    let name = reqwest::header::HeaderName::from_bytes(auth.header_name().as_bytes())
+        .map_err(|err| Error::ResultError(format!("invalid auth header: {err}")))?;
+    let mut value = reqwest::header::HeaderValue::from_str(&auth.header_value())
+        .map_err(|err| Error::ResultError(format!("invalid auth header: {err}")))?;
+    value.set_sensitive(true);
+
+    let mut headers = reqwest::header::HeaderMap::new();
+    let _ = headers.insert(name, value);
+
+    Ok(apply(builder, headers))
+}
+
+#[inline]
+fn client_blocking(proxy: Option<&str>) -> std::result::Result<reqwest::blocking::Client, Error> {
+    client_blocking_builder(proxy).build().map_err(Error::from)
+}
+
+#[inline]
+fn client_blocking_builder(proxy: Option<&str>) -> reqwest::blocking::ClientBuilder {
+    ReqwestRequest::<Get>::proxy(
+        proxy,
+        reqwest::blocking::Client::builder().user_agent(default_user_agent()),
+        reqwest::blocking::ClientBuilder::proxy,
+    )
+}
+
+#[inline]
+fn default_user_agent() -> &'static str {
+    DEFAULT_USER_AGENT.get_or_init(|| format!("rqlite_client/{}", crate::BUILD_TIME))
+}
+
+/// default HTTP User-Agent header
+static DEFAULT_USER_AGENT: OnceLock<String> = OnceLock::new();
+
+/// blocking client singleton
+static REQWEST_CLIENT_BLOCKING: OnceLock<reqwest::blocking::Client> = OnceLock::new();
+
+impl ReqwestRequest<Delete> {
+    /// Run `Request` for `Query` with the JSON `body` and get the text of the response body
+    ///
+    /// See [`Query::request_remove()`](crate::Query::request_remove)
+    ///
+    /// # Errors
+    ///
+    /// [`Error`] on failing request or [`Error::HttpError`] with the text of the response body
+    /// on HTTP status not 2xx
+    ///
+    pub fn run_delete<T: State, C: Deref<Target = Connection>>(
+        &self,
+        query: &Query<'_, T, C>,
+        body: &Value,
+    ) -> std::result::Result<String, Error> {
+        log::debug!("[DELETE] {}", query.url_redacted());
+        tracing::debug!("[DELETE] {}", query.url_redacted());
+
+        let r = self.client_blocking()?.delete(query.url()).json(body);
+        let r = auth_header(
+            r,
+            query.connection_ref().auth(),
+            reqwest::blocking::RequestBuilder::headers,
+        )?;
+
+        let r = if let Some(timeout) = query.timeout_request() {
+            r.timeout(*timeout)
+        } else {
+            r
+        };
+
+        let r = r.send().map_err(Error::from)?;
+        let status = r.status();
+        let text = r.text().map_err(Error::from)?;
+
+        if status.is_success() {
+            Ok(text)
+        } else {
+            let msg = text.trim();
+            Err(Error::HttpError(
+                status.as_u16(),
+                if msg.is_empty() {
+                    status.canonical_reason().unwrap_or_default().to_string()
+                } else {
+                    msg.to_string()
+                },
+            ))
+        }
+    }
+}
+
+impl ReqwestRequest<Get> {
+    /// Run `Request` for `Query`, see [`RequestBuilder::run()`]
+    pub(crate) fn run_query<T: State, C: Deref<Target = Connection>>(
+        &self,
+        query: &Query<'_, T, C>,
+    ) -> Result {
+        log::debug!("[GET] {}: {}", query.url_redacted(), query.sql_redacted());
+        tracing::debug!("[GET] {}: {}", query.url_redacted(), query.sql_redacted());
+
+        Response::try_from(self.send(query)?)
+    }
+
+    /// Run `Request` for `Query` and stream the rows of the response
+    ///
+    /// See [`Query::request_stream()`]
+    ///
+    /// # Errors
+    ///
+    /// [`Error`] on failing request or HTTP status not 2xx
+    ///
+    pub fn run_stream<T: State, C: Deref<Target = Connection>>(
+        &self,
+        query: &Query<'_, T, C>,
+    ) -> std::result::Result<RowStream, Error> {
+        log::debug!(
+            "[GET] stream {}: {}",
+            query.url_redacted(),
+            query.sql_redacted()
+        );
+        tracing::debug!(
+            "[GET] stream {}: {}",
+            query.url_redacted(),
+            query.sql_redacted()
+        );
+
+        RowStream::try_from(self.send(query)?)
+    }
+
+    /// Run `Request` for `Query` and get a reader of the raw response body
+    ///
+    /// See [`Query::request_backup()`](crate::Query::request_backup)
+    ///
+    /// # Errors
+    ///
+    /// [`Error`] on failing request or HTTP status not 2xx
+    ///
+    pub fn run_read<T: State, C: Deref<Target = Connection>>(
+        &self,
+        query: &Query<'_, T, C>,
+    ) -> std::result::Result<Box<dyn std::io::Read + Send>, Error> {
+        log::debug!("[GET] read {}", query.url_redacted());
+        tracing::debug!("[GET] read {}", query.url_redacted());
+
+        let r = self.send(query)?;
+        let status = r.status();
+
+        if !status.is_success() {
+            return Err(Error::HttpError(
+                status.as_u16(),
+                status.canonical_reason().unwrap_or_default().to_string(),
+            ));
+        }
+
+        Ok(Box::new(r))
+    }
+
+    fn send<T: State, C: Deref<Target = Connection>>(
+        &self,
+        query: &Query<'_, T, C>,
+    ) -> std::result::Result<reqwest::blocking::Response, Error> {
+        let r = self
+            .client_blocking()?
+            .get(query.url())
+            .header(reqwest::header::CONTENT_TYPE, "application/json");
+        let r = auth_header(
+            r,
+            query.connection_ref().auth(),
+            reqwest::blocking::RequestBuilder::headers,
+        )?;
+
+        let r = if let Some(timeout) = query.timeout_request() {
+            r.timeout(*timeout)
+        } else {
+            r
+        };
+
+        r.send().map_err(Error::from)
+    }
+
+    /// Run asynchronous `Request` for `Query`, see [`AsyncRequestBuilder::run_async()`]
+    pub(crate) fn run_query_async<T: State, C: Deref<Target = Connection>>(
+        &self,
+        query: &Query<'_, T, C>,
+    ) -> ResponseFuture {
+        log::debug!("[GET] {}: {}", query.url_redacted(), query.sql_redacted());
+        tracing::debug!("[GET] {}: {}", query.url_redacted(), query.sql_redacted());
+
+        let client = self.client();
+        let url = query.url();
+        let timeout = query.timeout_request().copied();
+        let auth = query.connection_ref().auth().cloned();
+
+        Box::pin(async move {
+            let r = client?
+                .get(url)
+                .header(reqwest::header::CONTENT_TYPE, "application/json");
+            let r = auth_header(r, auth.as_ref(), reqwest::RequestBuilder::headers)?;
+
+            let r = if let Some(timeout) = timeout {
+                r.timeout(timeout)
+            } else {
+                r
+            };
+
+            let r = r.send().await.map_err(Error::from)?;
+
+            Response::try_from_reqwest(r).await
+        })
+    }
+}
+
+impl ReqwestRequest<Post> {
+    /// Run `Request` for `Query`, see [`RequestBuilder::run()`]
+    pub(crate) fn run_query<T: State, C: Deref<Target = Connection>>(
+        &self,
+        query: &Query<'_, T, C>,
+    ) -> Result {
+        log::debug!("[POST] {}: {}", query.url_redacted(), query.sql_redacted());
+        tracing::debug!("[POST] {}: {}", query.url_redacted(), query.sql_redacted());
+
+        Response::try_from(self.send(query)?)
+    }
+
+    /// Run `Request` for `Query` and stream the rows of the response
+    ///
+    /// See [`Query::request_stream()`]
+    ///
+    /// # Errors
+    ///
+    /// [`Error`] on failing request or HTTP status not 2xx
+    ///
+    pub fn run_stream<T: State, C: Deref<Target = Connection>>(
+        &self,
+        query: &Query<'_, T, C>,
+    ) -> std::result::Result<RowStream, Error> {
+        log::debug!(
+            "[POST] stream {}: {}",
+            query.url_redacted(),
+            query.sql_redacted()
+        );
+        tracing::debug!(
+            "[POST] stream {}: {}",
+            query.url_redacted(),
+            query.sql_redacted()
+        );
+
+        RowStream::try_from(self.send(query)?)
+    }
+
+    /// Run `Request` for `Query` with the body from `reader` and get a reader of the raw response body
+    ///
+    /// See [`Query::request_load()`](crate::Query::request_load)
+    ///
+    /// # Errors
+    ///
+    /// [`Error`] on failing request or HTTP status not 2xx
+    ///
+    pub fn run_upload<
+        T: State,
+        C: Deref<Target = Connection>,
+        R: std::io::Read + Send + 'static,
+    >(
+        &self,
+        query: &Query<'_, T, C>,
+        content_type: &str,
+        reader: R,
+    ) -> std::result::Result<Box<dyn std::io::Read + Send>, Error> {
+        log::debug!("[POST] upload {}: {content_type}", query.url_redacted());
+        tracing::debug!("[POST] upload {}: {content_type}", query.url_redacted());
+
+        let r = self
+            .client_blocking()?
+            .post(query.url())
+            .header(reqwest::header::CONTENT_TYPE, content_type)
+            .body(reqwest::blocking::Body::new(reader));
+        let r = auth_header(
+            r,
+            query.connection_ref().auth(),
+            reqwest::blocking::RequestBuilder::headers,
+        )?;
+
+        let r = if let Some(timeout) = query.timeout_request() {
+            r.timeout(*timeout)
+        } else {
+            r
+        };
+
+        let r = r.send().map_err(Error::from)?;
+        let status = r.status();
+
+        if !status.is_success() {
+            return Err(Error::HttpError(
+                status.as_u16(),
+                status.canonical_reason().unwrap_or_default().to_string(),
+            ));
+        }
+
+        Ok(Box::new(r))
+    }
+
+    fn send<T: State, C: Deref<Target = Connection>>(
+        &self,
+        query: &Query<'_, T, C>,
+    ) -> std::result::Result<reqwest::blocking::Response, Error> {
+        let r = self.client_blocking()?.post(query.url()).json(query.sql());
+        let r = auth_header(
+            r,
+            query.connection_ref().auth(),
+            reqwest::blocking::RequestBuilder::headers,
+        )?;
+
+        let r = if let Some(timeout) = query.timeout_request() {
+            r.timeout(*timeout)
+        } else {
+            r
+        };
+
+        r.send().map_err(Error::from)
+    }
+
+    /// Run asynchronous `Request` for `Query`, see [`AsyncRequestBuilder::run_async()`]
+    pub(crate) fn run_query_async<T: State, C: Deref<Target = Connection>>(
+        &self,
+        query: &Query<'_, T, C>,
+    ) -> ResponseFuture {
+        log::debug!("[POST] {}: {}", query.url_redacted(), query.sql_redacted());
+        tracing::debug!("[POST] {}: {}", query.url_redacted(), query.sql_redacted());
+
+        let client = self.client();
+        let url = query.url();
+        let timeout = query.timeout_request().copied();
+        let auth = query.connection_ref().auth().cloned();
+        let sql: Vec<Value> = query.sql().clone();
+
+        Box::pin(async move {
+            let r = client?.post(url).json(&sql);
+            let r = auth_header(r, auth.as_ref(), reqwest::RequestBuilder::headers)?;
+
+            let r = if let Some(timeout) = timeout {
+                r.timeout(timeout)
+            } else {
+                r
+            };
+
+            let r = r.send().await.map_err(Error::from)?;
+
+            Response::try_from_reqwest(r).await
+        })
+    }
+}
+
+impl<T> Default for ReqwestRequest<T>
+where
+    T: RequestType,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> From<&Connection> for ReqwestRequest<T>
+where
+    T: RequestType,
+{
+    fn from(connection: &Connection) -> Self {
+        Self::from_connection(connection)
+    }
+}
+
+impl<T> From<Connection> for ReqwestRequest<T>
+where
+    T: RequestType,
+{
+    fn from(connection: Connection) -> Self {
+        Self::from_connection(&connection)
+    }
+}
+
+impl From<Delete> for ReqwestRequest<Delete> {
+    fn from(_value: Delete) -> Self {
+        ReqwestRequest::<Delete>::new()
+    }
+}
+
+impl From<Get> for ReqwestRequest<Get> {
+    fn from(_value: Get) -> Self {
+        ReqwestRequest::<Get>::new()
+    }
+}
+
+impl From<Post> for ReqwestRequest<Post> {
+    fn from(_value: Post) -> Self {
+        ReqwestRequest::<Post>::new()
+    }
+}
+
+impl<S> RequestBuilder<S> for ReqwestRequest<Get>
+where
+    S: State,
+{
+    #[inline]
+    fn run(&self, query: &Query<S>) -> Result {
+        self.run_query(query)
+    }
+}
+
+impl<S> RequestBuilder<S> for ReqwestRequest<Post>
+where
+    S: State,
+{
+    #[inline]
+    fn run(&self, query: &Query<S>) -> Result {
+        self.run_query(query)
+    }
+}
+
+impl<S> AsyncRequestBuilder<S> for ReqwestRequest<Get>
+where
+    S: State,
+{
+    #[inline]
+    fn run_async<C>(&self, query: &Query<'_, S, C>) -> ResponseFuture
+    where
+        C: Deref<Target = Connection>,
+    {
+        self.run_query_async(query)
+    }
+}
+
+impl<S> AsyncRequestBuilder<S> for ReqwestRequest<Post>
+where
+    S: State,
+{
+    #[inline]
+    fn run_async<C>(&self, query: &Query<'_, S, C>) -> ResponseFuture
+    where
+        C: Deref<Target = Connection>,
+    {
+        self.run_query_async(query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::{
+        request_type::{Get, Post},
+        Connection, ReqwestRequest,
+    };
+
+    #[test]
+    fn reqwest_request_get_test() {
+        let request = ReqwestRequest::<Get>::new();
+        assert!(request.proxy.is_none());
+        assert!(request.client.read().unwrap().is_none());
+        assert!(ReqwestRequest::from(Get).proxy.is_none());
+    }
+
+    #[test]
+    fn reqwest_request_post_test() {
+        let request = ReqwestRequest::<Post>::new();
+        assert!(request.proxy.is_none());
+        assert!(request.client_blocking.read().unwrap().is_none());
+        assert!(ReqwestRequest::from(Post).proxy.is_none());
+    }
+
+    #[test]
+    fn reqwest_request_connection_test() {
+        let c = Connection::new("http://example.com");
+        #[cfg(feature = "url")]
+        let c = c.unwrap();
+        let c = c.set_proxy("http://proxy.example.com:12345");
+
+        assert_eq!(
+            ReqwestRequest::<Get>::from(&c).proxy.as_deref(),
+            Some("http://proxy.example.com:12345")
+        );
+        assert!(c.reqwest_client().read().unwrap().is_none());
+        let _ = ReqwestRequest::<Post>::from(&c).client().unwrap();
+        assert!(c.reqwest_client().read().unwrap().is_some());
+        assert!(Arc::ptr_eq(
+            &ReqwestRequest::<Get>::from(&c).client,
+            c.reqwest_client()
+        ));
+        assert!(Arc::ptr_eq(
+            ReqwestRequest::<Get>::from(&c)
+                .client_blocking_shared
+                .as_ref()
+                .unwrap(),
+            c.reqwest_client_blocking()
+        ));
+        assert!(!Arc::ptr_eq(
+            ReqwestRequest::<Get>::from(&c)
+                .client_blocking_shared
+                .as_ref()
+                .unwrap(),
+            c.clone()
+                .set_proxy("http://proxy2.example.com:12345")
+                .reqwest_client_blocking()
+        ));
+    }
+
+    #[cfg(any(feature = "reqwest_tls", feature = "reqwest_webpki"))]
+    #[test]
+    fn reqwest_request_tls_config_test() {
+        let c = Connection::new("https://example.com");
+        #[cfg(feature = "url")]
+        let c = c.unwrap();
+        let c = c.set_tls_config(crate::TlsConfig::new()).unwrap();
+
+        let request = ReqwestRequest::<Get>::from(&c);
+        assert!(request.tls_config.is_some());
+        assert!(request.client().is_ok());
+        assert!(request.client_blocking().is_ok());
+    }
+}
@@ -2,9 +2,11 @@
 
 use crate::Error;
 pub use query::Query;
+pub use stream::RowStream;
 
 pub mod mapping;
 pub mod query;
+mod stream;
 
 /// Result type with [`Response`] or [`Error`]
 pub type Result = std::result::Result<Response, Error>;
@@ -12,6 +14,9 @@ pub type Result = std::result::Result<Response, Error>;
 /// [`Response`] `enum` for handling different __rqlited__ database server responses
 #[derive(Debug, PartialEq)]
 pub enum Response {
+    /// Response of [`monitor::Endpoint::DebugVars`](crate::monitor::response::DebugVars) (feature `monitor`)
+    #[cfg(feature = "monitor")]
+    DebugVars(Box<crate::monitor::response::DebugVars>),
     /// Response of [`monitor::Endpoint::Nodes`](crate::monitor::response::Nodes) (feature `monitor`)
     #[cfg(feature = "monitor")]
     Node(crate::monitor::response::Nodes),
@@ -24,44 +29,127 @@ pub enum Response {
     Query(Query),
     /// Response of [`monitor::Endpoint::Status`](crate::monitor::response::Status) (feature `monitor`)
     #[cfg(feature = "monitor")]
-    Status(crate::monitor::response::Status),
+    Status(Box<crate::monitor::response::Status>),
 }
 
-#[cfg(feature = "ureq")]
-impl TryFrom<ureq::Response> for Response {
-    type Error = Error;
-
-    fn try_from(response: ureq::Response) -> std::result::Result<Self, Self::Error> {
-        let status = response.status();
-
-        if !(200..300).contains(&status) {
-            return Err(Error::HttpError(status, response.status_text().to_string()));
-        }
-
-        let mut value = None;
-        let mut content = None;
-
-        // checks
-        if let Some(content_type) = response.header("Content-Type") {
-            let content_length = response
-                .header("Content-Length")
-                .and_then(|s| s.parse::<usize>().ok());
+/// Kind of the response body detected by the HTTP headers
+#[cfg(any(feature = "reqwest", feature = "ureq"))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Content {
+    Json,
+    Text,
+}
 
+#[cfg(any(feature = "reqwest", feature = "ureq"))]
+impl Content {
+    /// Check `Content-Type` and `Content-Length` headers of a response
+    fn detect(
+        content_type: Option<&str>,
+        content_length: Option<usize>,
+    ) -> std::result::Result<Self, Error> {
+        if let Some(content_type) = content_type {
             if content_type.starts_with("application/json") {
-                value = Some(response.into_json::<crate::Value>().map_err(Error::from)?);
+                Ok(Self::Json)
             } else if let Some(content_length) = content_length {
                 if content_length <= 200 && content_type.starts_with("text/plain") {
-                    content = response.into_string().ok();
+                    Ok(Self::Text)
                 } else {
-                    return Err("content-length too big".into());
+                    Err("content-length too big".into())
                 }
             } else {
-                return Err("unsupported response".into());
+                Err("unsupported response".into())
             }
         } else {
-            return Err("unsupported response".into());
+            Err("unsupported response".into())
         }
+    }
+}
+
+impl Response {
+    /// Convert into [`monitor::response::DebugVars`](crate::monitor::response::DebugVars) (feature `monitor`)
+    ///
+    /// # Errors
+    ///
+    /// [`Error::ResultError`] if it isn't [`Response::DebugVars`]
+    ///
+    #[cfg(feature = "monitor")]
+    pub fn into_debug_vars(
+        self,
+    ) -> std::result::Result<crate::monitor::response::DebugVars, Error> {
+        self.try_into()
+    }
+
+    /// Convert into [`monitor::response::Nodes`](crate::monitor::response::Nodes) (feature `monitor`)
+    ///
+    /// # Errors
+    ///
+    /// [`Error::ResultError`] if it isn't [`Response::Node`]
+    ///
+    #[cfg(feature = "monitor")]
+    pub fn into_nodes(self) -> std::result::Result<crate::monitor::response::Nodes, Error> {
+        self.try_into()
+    }
+
+    /// Convert into [`Query`]
+    ///
+    /// # Errors
+    ///
+    /// [`Error::ResultError`] if it isn't [`Response::Query`]
+    ///
+    pub fn into_query(self) -> std::result::Result<Query, Error> {
+        self.try_into()
+    }
+
+    /// Convert into [`monitor::response::Readyz`](crate::monitor::response::Readyz) (feature `monitor`)
+    ///
+    /// # Errors
+    ///
+    /// [`Error::ResultError`] if it isn't [`Response::Readyz`]
+    ///
+    #[cfg(feature = "monitor")]
+    pub fn into_readyz(self) -> std::result::Result<crate::monitor::response::Readyz, Error> {
+        self.try_into()
+    }
+
+    /// Convert into [`monitor::response::Status`](crate::monitor::response::Status) (feature `monitor`)
+    ///
+    /// # Errors
+    ///
+    /// [`Error::ResultError`] if it isn't [`Response::Status`]
+    ///
+    #[cfg(feature = "monitor")]
+    pub fn into_status(self) -> std::result::Result<crate::monitor::response::Status, Error> {
+        self.try_into()
+    }
 
+    /// Error for a not matching conversion into the `expected` response
+    fn mismatch(&self, expected: &str) -> Error {
+        let found = match self {
+            #[cfg(feature = "monitor")]
+            Response::DebugVars(_) => "debug vars",
+            #[cfg(feature = "monitor")]
+            Response::Node(_) => "nodes",
+            Response::Query(_) => "query",
+            #[cfg(feature = "monitor")]
+            Response::Readyz(_) => "readyz",
+            #[cfg(feature = "monitor")]
+            Response::Status(_) => "status",
+        };
+
+        Error::ResultError(format!(
+            "expected {expected} response, found {found} response"
+        ))
+    }
+
+    /// Parse [`Response`] from the decoded JSON `value` or plain text `content` of a response body
+    ///
+    /// This is the common part of the response handling for the provided HTTP clients.
+    ///
+    #[cfg(any(feature = "reqwest", feature = "ureq"))]
+    fn try_from_parts(
+        value: Option<crate::Value>,
+        content: Option<String>,
+    ) -> std::result::Result<Self, Error> {
         // response type parsing - feature monitor
         #[cfg(feature = "monitor")]
         if let Some(value) = value {
@@ -71,7 +159,11 @@ impl TryFrom<ureq::Response> for Response {
                     .map_err(Error::from)
             } else if value.get("build").is_some() {
                 serde_json::from_value::<crate::monitor::response::Status>(value)
-                    .map(Response::Status)
+                    .map(|status| Response::Status(Box::new(status)))
+                    .map_err(Error::from)
+            } else if value.get("cmdline").is_some() || value.get("memstats").is_some() {
+                serde_json::from_value::<crate::monitor::response::DebugVars>(value)
+                    .map(|debug_vars| Response::DebugVars(Box::new(debug_vars)))
                     .map_err(Error::from)
             } else if value.get("nodes").is_some() {
                 serde_json::from_value::<crate::monitor::response::NodesV2>(value)
@@ -108,54 +200,204 @@ impl TryFrom<ureq::Response> for Response {
             Err("unsupported response".into())
         }
     }
+
+    /// Decode [`Response`] of asynchronous [`reqwest::Response`]
+    ///
+    /// Requires feature `reqwest`.
+    ///
+    /// # Errors
+    ///
+    /// [`Error`] on HTTP status not 2xx or unsupported response body
+    ///
+    #[cfg(feature = "reqwest")]
+    pub async fn try_from_reqwest(response: reqwest::Response) -> std::result::Result<Self, Error> {
+        let status = response.status();
+
+        if !status.is_success() {
+            return Err(Error::HttpError(
+                status.as_u16(),
+                status.canonical_reason().unwrap_or_default().to_string(),
+            ));
+        }
+
+        let content = Content::detect(
+            response
+                .headers()
+                .get(reqwest::header::CONTENT_TYPE)
+                .and_then(|v| v.to_str().ok()),
+            response
+                .content_length()
+                .and_then(|l| usize::try_from(l).ok()),
+        )?;
+
+        match content {
+            Content::Json => Self::try_from_parts(
+                Some(response.json::<crate::Value>().await.map_err(Error::from)?),
+                None,
+            ),
+            Content::Text => Self::try_from_parts(None, response.text().await.ok()),
+        }
+    }
+}
+
+#[cfg(feature = "reqwest")]
+impl TryFrom<reqwest::blocking::Response> for Response {
+    type Error = Error;
+
+    fn try_from(response: reqwest::blocking::Response) -> std::result::Result<Self, Self::Error> {
+        let status = response.status();
+
+        if !status.is_success() {
+            return Err(Error::HttpError(
+                status.as_u16(),
+                status.canonical_reason().unwrap_or_default().to_string(),
+            ));
+        }
+
+        let content = Content::detect(
+            response
+                .headers()
+                .get(reqwest::header::CONTENT_TYPE)
+                .and_then(|v| v.to_str().ok()),
+            response
+                .content_length()
+                .and_then(|l| usize::try_from(l).ok()),
+        )?;
+
+        match content {
+            Content::Json => Self::try_from_parts(
+                Some(response.json::<crate::Value>().map_err(Error::from)?),
+                None,
+            ),
+            Content::Text => Self::try_from_parts(None, response.text().ok()),
+        }
+    }
+}
+
+#[cfg(feature = "ureq")]
+impl TryFrom<ureq::Response> for Response {
+    type Error = Error;
+
+    fn try_from(response: ureq::Response) -> std::result::Result<Self, Self::Error> {
+        let status = response.status();
+
+        if !(200..300).contains(&status) {
+            return Err(Error::HttpError(status, response.status_text().to_string()));
+        }
+
+        let content = Content::detect(
+            response.header("Content-Type"),
+            response
+                .header("Content-Length")
+                .and_then(|s| s.parse::<usize>().ok()),
+        )?;
+
+        match content {
+            Content::Json => Self::try_from_parts(
+                Some(response.into_json::<crate::Value>().map_err(Error::from)?),
+                None,
+            ),
+            Content::Text => Self::try_from_parts(None, response.into_string().ok()),
+        }
+    }
 }
 
 #[allow(unreachable_patterns)]
-impl From<Response> for Query {
-    fn from(response: Response) -> Self {
+impl TryFrom<Response> for Query {
+    type Error = Error;
+
+    fn try_from(response: Response) -> std::result::Result<Self, Self::Error> {
+        match response {
+            Response::Query(r) => Ok(r),
+            response => Err(response.mismatch("query")),
+        }
+    }
+}
+
+#[cfg(feature = "monitor")]
+impl TryFrom<Response> for crate::monitor::response::DebugVars {
+    type Error = Error;
+
+    fn try_from(response: Response) -> std::result::Result<Self, Self::Error> {
         match response {
-            Response::Query(r) => r,
-            _ => panic!("not matching"),
+            Response::DebugVars(r) => Ok(*r),
+            response => Err(response.mismatch("debug vars")),
         }
     }
 }
 
 #[cfg(feature = "monitor")]
-impl From<Response> for crate::monitor::response::Nodes {
-    fn from(response: Response) -> Self {
+impl TryFrom<Response> for crate::monitor::response::Nodes {
+    type Error = Error;
+
+    fn try_from(response: Response) -> std::result::Result<Self, Self::Error> {
         match response {
-            Response::Node(r) => r,
-            _ => panic!("not matching"),
+            Response::Node(r) => Ok(r),
+            response => Err(response.mismatch("nodes")),
         }
     }
 }
 
 #[cfg(feature = "monitor")]
-impl From<Response> for crate::monitor::response::NodesV2 {
-    fn from(response: Response) -> Self {
+impl TryFrom<Response> for crate::monitor::response::NodesV2 {
+    type Error = Error;
+
+    fn try_from(response: Response) -> std::result::Result<Self, Self::Error> {
         match response {
-            Response::Node(r) => r.into(),
-            _ => panic!("not matching"),
+            Response::Node(r) => Ok(r.into()),
+            response => Err(response.mismatch("nodes")),
         }
     }
 }
 
 #[cfg(feature = "monitor")]
-impl From<Response> for crate::monitor::response::Readyz {
-    fn from(response: Response) -> Self {
+impl TryFrom<Response> for crate::monitor::response::Readyz {
+    type Error = Error;
+
+    fn try_from(response: Response) -> std::result::Result<Self, Self::Error> {
         match response {
-            Response::Readyz(r) => r,
-            _ => panic!("not matching"),
+            Response::Readyz(r) => Ok(r),
+            response => Err(response.mismatch("readyz")),
         }
     }
 }
 
 #[cfg(feature = "monitor")]
-impl From<Response> for crate::monitor::response::Status {
-    fn from(response: Response) -> Self {
+impl TryFrom<Response> for crate::monitor::response::Status {
+    type Error = Error;
+
+    fn try_from(response: Response) -> std::result::Result<Self, Self::Error> {
         match response {
-            Response::Status(r) => r,
-            _ => panic!("not matching"),
+            Response::Status(r) => Ok(*r),
+            response => Err(response.mismatch("status")),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Query, Response};
+
+    #[test]
+    fn try_from_response_test() {
+        let response = Response::Query(serde_json::from_str::<Query>(r#"{"results":[]}"#).unwrap());
+
+        assert!(response.into_query().is_ok());
+    }
+
+    #[cfg(feature = "monitor")]
+    #[test]
+    fn try_from_response_mismatch_test() {
+        use crate::monitor::response::{Readyz, Status};
+
+        let response = Response::Status(Box::new(Status::from(crate::Value::Null)));
+        let err = Query::try_from(response).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "expected query response, found status response"
+        );
+
+        let response = Response::Query(serde_json::from_str::<Query>(r#"{"results":[]}"#).unwrap());
+        assert!(Readyz::try_from(response).is_err());
+    }
+}
@@ -1,17 +1,21 @@
 //! [`Mapping`] `enum` for different JSON [`Response`](crate::Response)
 
 pub use associative::Associative;
+pub use deserializer::StandardDeserializer;
 pub use empty::Empty;
 pub use error::Error;
 pub use execute::Execute;
+pub use row::{FromRow, Row};
 pub use standard::Standard;
 pub use timed::Timed;
 
 mod associative;
+mod deserializer;
 mod empty;
 mod error;
 mod execute;
 mod macros;
+mod row;
 mod standard;
 mod timed;
 
@@ -39,7 +43,7 @@ mod timed;
 /// let response_result: Option<Result> = None;
 ///
 /// if let Some(Ok(response_result)) = response_result {
-///     let query = response::Query::from(response_result);
+///     let query = response::Query::try_from(response_result)?;
 ///     for result in query.results() {
 ///         match result {
 ///             Mapping::Error(err) => err.to_string(),
@@ -47,6 +51,7 @@ mod timed;
 ///         };
 ///     }
 /// }
+/// # Ok::<(), rqlite_client::Error>(())
 /// ```
 ///
 /// Convert [`Result`] to an own data struct
@@ -79,12 +84,13 @@ mod timed;
 /// let response_result: Option<Result> = None;
 ///
 /// if let Some(Ok(response_result)) = response_result {
-///     let query = response::Query::from(response_result);
+///     let query = response::Query::try_from(response_result)?;
 ///     let my_data = query
 ///         .results()
 ///         .filter_map(|r| MyData::try_from(r).ok())
 ///         .collect::<Vec<MyData>>();
 /// }
+/// # Ok::<(), rqlite_client::Error>(())
 /// ```
 ///
 #[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -101,3 +107,29 @@ pub enum Mapping {
     /// See [`Empty`]
     Empty(Empty),
 }
+
+impl Mapping {
+    /// Decode all rows with [`FromRow`]
+    ///
+    /// [`Execute`] and [`Empty`] have no rows.
+    ///
+    /// # Errors
+    ///
+    /// [`crate::Error::ResultError`] for [`Error`] or if a row can't be decoded
+    pub fn rows<T>(&self) -> Result<Vec<T>, crate::Error>
+    where
+        T: FromRow,
+    {
+        match self {
+            Mapping::Associative(associative) => associative
+                .row_iter()
+                .map(|row| T::from_row(&row))
+                .collect(),
+            Mapping::Error(err) => Err(err.error.as_str().into()),
+            Mapping::Execute(_) | Mapping::Empty(_) => Ok(Vec::new()),
+            Mapping::Standard(standard) => {
+                standard.row_iter().map(|row| T::from_row(&row)).collect()
+            }
+        }
+    }
+}
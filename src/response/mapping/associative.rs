@@ -4,7 +4,7 @@ use std::collections::HashMap;
 
 use crate::{response::mapping, DataType, Value};
 
-use super::timed::Timed;
+use super::{timed::Timed, Row};
 
 /// `Associative` result
 ///
@@ -43,6 +43,23 @@ impl Timed for Associative {
     }
 }
 
+impl Associative {
+    /// Get [`Row`] view of row `row_index`
+    #[must_use]
+    pub fn row(&self, row_index: usize) -> Option<Row<'_>> {
+        self.rows
+            .get(row_index)
+            .map(|row| Row::associative(row, &self.types))
+    }
+
+    /// Iterator over the [`Row`] views of all rows
+    pub fn row_iter(&self) -> impl Iterator<Item = Row<'_>> {
+        self.rows
+            .iter()
+            .map(|row| Row::associative(row, &self.types))
+    }
+}
+
 impl From<mapping::Standard> for Associative {
     fn from(standard: mapping::Standard) -> Self {
         let columns = standard.columns;
@@ -53,17 +70,15 @@ impl From<mapping::Standard> for Associative {
             for row in rows {
                 let mut hm = HashMap::new();
 
-                for (idx, column) in columns.iter().enumerate() {
-                    if let Some(value) = row.get(idx) {
-                        let _ = hm.insert(column.to_string(), value.clone());
-                    }
+                for (column, value) in columns.iter().zip(row) {
+                    let _ = hm.insert(column.clone(), value);
                 }
                 v.push(hm);
             }
 
             for (idx, column) in columns.iter().enumerate() {
                 if let Some(t) = standard.types.get(idx) {
-                    let _ = hm_typ.insert(column.to_string(), *t);
+                    let _ = hm_typ.insert(column.clone(), *t);
                 }
             }
         }
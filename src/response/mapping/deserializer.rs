@@ -0,0 +1,365 @@
+//! `deserializer`
+
+use serde::de::{self, DeserializeSeed, IntoDeserializer, MapAccess, SeqAccess, Visitor};
+use serde::forward_to_deserialize_any;
+
+use crate::Value;
+
+use super::Standard;
+
+/// [`serde::Deserializer`] of the rows of a [`Standard`] result
+///
+/// The rows are deserialized as a sequence directly from `columns` and `values`
+/// without a conversion into [`Associative`](super::Associative).
+/// The values are moved out of the [`Standard`] result and not cloned.
+///
+/// Each row is deserialized
+///
+/// * as map of column name to value into a struct or map
+/// * as sequence of values into a tuple or `Vec`
+///
+/// # Usage
+///
+/// ```
+/// use serde::Deserialize;
+/// use rqlite_client::response::mapping::{Standard, StandardDeserializer};
+///
+/// let standard = Standard::new()
+///     .push_column("id")
+///     .push_column("name")
+///     .push_values(vec![1.into(), "fiona".into()]);
+///
+/// let rows = Vec::<(i64, String)>::deserialize(StandardDeserializer::new(standard))?;
+/// assert_eq!(rows, vec![(1, "fiona".to_string())]);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+///
+#[derive(Debug)]
+pub struct StandardDeserializer {
+    columns: Vec<String>,
+    rows: std::vec::IntoIter<Vec<Value>>,
+}
+
+impl StandardDeserializer {
+    /// Create `StandardDeserializer` for the rows of `standard`
+    #[must_use]
+    pub fn new(standard: Standard) -> Self {
+        Self {
+            columns: standard.columns,
+            rows: standard.values.unwrap_or_default().into_iter(),
+        }
+    }
+}
+
+impl<'de> de::Deserializer<'de> for StandardDeserializer {
+    type Error = serde_json::Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_seq(self)
+    }
+
+    fn deserialize_ignored_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_some(self)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct enum identifier
+    }
+}
+
+impl<'de> SeqAccess<'de> for StandardDeserializer {
+    type Error = serde_json::Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        self.rows
+            .next()
+            .map(|values| {
+                seed.deserialize(RowDeserializer {
+                    columns: &self.columns,
+                    values: values.into_iter(),
+                })
+            })
+            .transpose()
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.rows.len())
+    }
+}
+
+/// [`serde::Deserializer`] of a single row of [`StandardDeserializer`]
+struct RowDeserializer<'c> {
+    columns: &'c [String],
+    values: std::vec::IntoIter<Value>,
+}
+
+impl<'de> de::Deserializer<'de> for RowDeserializer<'_> {
+    type Error = serde_json::Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_map(visitor)
+    }
+
+    fn deserialize_ignored_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_map<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_map(RowMap {
+            columns: self.columns.iter(),
+            value: None,
+            values: self.values,
+        })
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        let len = self.values.len();
+        let mut seq = RowSeq(self.values);
+        let value = visitor.visit_seq(&mut seq)?;
+
+        if seq.0.len() == 0 {
+            Ok(value)
+        } else {
+            Err(de::Error::invalid_length(len, &"fewer values in row"))
+        }
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        _name: &'static str,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_map(visitor)
+    }
+
+    fn deserialize_tuple<V>(self, _len: usize, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_seq(visitor)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct enum identifier
+    }
+}
+
+/// [`MapAccess`] of column name to value of a row
+struct RowMap<'c> {
+    columns: std::slice::Iter<'c, String>,
+    value: Option<Value>,
+    values: std::vec::IntoIter<Value>,
+}
+
+impl<'de> MapAccess<'de> for RowMap<'_> {
+    type Error = serde_json::Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        match (self.columns.next(), self.values.next()) {
+            (Some(column), Some(value)) => {
+                self.value = Some(value);
+                seed.deserialize(column.as_str().into_deserializer())
+                    .map(Some)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        match self.value.take() {
+            Some(value) => seed.deserialize(value),
+            None => Err(de::Error::custom("value is missing")),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.columns.len().min(self.values.len()))
+    }
+}
+
+/// [`SeqAccess`] of the values of a row
+struct RowSeq(std::vec::IntoIter<Value>);
+
+impl<'de> SeqAccess<'de> for RowSeq {
+    type Error = serde_json::Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        self.0
+            .next()
+            .map(|value| seed.deserialize(value))
+            .transpose()
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.0.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use serde::Deserialize;
+
+    use super::StandardDeserializer;
+    use crate::response::mapping::Standard;
+    use crate::{DataType, Value};
+
+    #[derive(Debug, PartialEq, serde::Deserialize)]
+    struct Person {
+        id: i64,
+        name: Option<String>,
+    }
+
+    fn standard() -> Standard {
+        Standard::new()
+            .push_column("id")
+            .push_column("name")
+            .push_type(DataType::Integer)
+            .push_type(DataType::Text)
+            .push_values(vec![Value::from(1), Value::from("fiona")])
+            .push_values(vec![Value::from(2), Value::Null])
+    }
+
+    #[test]
+    fn struct_test() {
+        let persons = Vec::<Person>::deserialize(StandardDeserializer::new(standard())).unwrap();
+
+        assert_eq!(
+            persons,
+            vec![
+                Person {
+                    id: 1,
+                    name: Some("fiona".to_string())
+                },
+                Person { id: 2, name: None },
+            ]
+        );
+    }
+
+    #[test]
+    fn seq_test() {
+        let tuples =
+            Vec::<(i64, Option<String>)>::deserialize(StandardDeserializer::new(standard()))
+                .unwrap();
+        assert_eq!(tuples, vec![(1, Some("fiona".to_string())), (2, None)]);
+
+        let values = Vec::<Vec<Value>>::deserialize(StandardDeserializer::new(standard())).unwrap();
+        assert_eq!(values[1], vec![Value::from(2), Value::Null]);
+
+        let err = Vec::<(i64,)>::deserialize(StandardDeserializer::new(standard())).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid length 2, expected fewer values in row"
+        );
+    }
+
+    #[test]
+    fn map_test() {
+        let maps =
+            Vec::<HashMap<String, Value>>::deserialize(StandardDeserializer::new(standard()))
+                .unwrap();
+
+        assert_eq!(maps.len(), 2);
+        assert_eq!(maps[0].get("name"), Some(&Value::from("fiona")));
+    }
+
+    #[test]
+    fn deserialize_rows_test() {
+        assert!(Standard::new()
+            .push_column("id")
+            .deserialize_rows::<Person>()
+            .unwrap()
+            .is_empty());
+
+        let err = standard()
+            .push_values(vec![Value::from("3")])
+            .deserialize_rows::<Person>()
+            .unwrap_err();
+        assert_eq!(err.to_string(), "invalid type: string \"3\", expected i64");
+    }
+}
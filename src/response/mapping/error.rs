@@ -1,5 +1,7 @@
 //! `error`
 
+use crate::ErrorKind;
+
 /// `Error` result
 ///
 /// ```json
@@ -21,6 +23,14 @@ pub struct Error {
     pub error: String,
 }
 
+impl Error {
+    /// Get [`ErrorKind`] classified from the error message
+    #[must_use]
+    pub fn kind(&self) -> ErrorKind {
+        ErrorKind::from(self.error.as_str())
+    }
+}
+
 impl std::error::Error for Error {}
 
 impl std::fmt::Display for Error {
@@ -0,0 +1,280 @@
+//! `row`
+
+use std::collections::HashMap;
+
+use crate::{DataType, Error, FromSqlValue, Value};
+
+/// View of a single [`Row`] of a [`Standard`](super::Standard) or
+/// [`Associative`](super::Associative) result
+///
+/// The columns are accessed by name with the same API for both result forms.
+///
+/// # Usage
+///
+/// ```
+/// use rqlite_client::response::mapping::Standard;
+/// use rqlite_client::DataType;
+///
+/// let standard = Standard::new()
+///     .push_column("id")
+///     .push_column("name")
+///     .push_type(DataType::Integer)
+///     .push_type(DataType::Text)
+///     .push_values(vec![1.into(), rqlite_client::Value::Null]);
+///
+/// let row = standard.row(0).unwrap();
+/// assert_eq!(row.get::<i64>("id"), 1);
+/// assert_eq!(row.get_opt::<String>("name")?, None);
+/// assert!(row.try_get::<String>("id").is_err());
+/// # Ok::<(), rqlite_client::Error>(())
+/// ```
+///
+#[derive(Clone, Copy, Debug)]
+pub struct Row<'a>(RowInner<'a>);
+
+#[derive(Clone, Copy, Debug)]
+enum RowInner<'a> {
+    Associative {
+        row: &'a HashMap<String, Value>,
+        types: &'a HashMap<String, DataType>,
+    },
+    Standard {
+        columns: &'a [String],
+        types: &'a [DataType],
+        values: &'a [Value],
+    },
+}
+
+impl<'a> Row<'a> {
+    /// Create `Row` of an [`Associative`](super::Associative) result
+    pub(crate) fn associative(
+        row: &'a HashMap<String, Value>,
+        types: &'a HashMap<String, DataType>,
+    ) -> Self {
+        Self(RowInner::Associative { row, types })
+    }
+
+    /// Create `Row` of a [`Standard`](super::Standard) result
+    pub(crate) fn standard(
+        columns: &'a [String],
+        types: &'a [DataType],
+        values: &'a [Value],
+    ) -> Self {
+        Self(RowInner::Standard {
+            columns,
+            types,
+            values,
+        })
+    }
+
+    /// Get [`DataType`] of `column`
+    #[must_use]
+    pub fn data_type(&self, column: &str) -> Option<DataType> {
+        match self.0 {
+            RowInner::Associative { types, .. } => types.get(column).copied(),
+            RowInner::Standard { columns, types, .. } => columns
+                .iter()
+                .position(|c| c == column)
+                .and_then(|index| types.get(index).copied()),
+        }
+    }
+
+    /// Check for no columns
+    #[must_use]
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Count of columns
+    #[must_use]
+    pub fn len(&self) -> usize {
+        match self.0 {
+            RowInner::Associative { row, .. } => row.len(),
+            RowInner::Standard { values, .. } => values.len(),
+        }
+    }
+
+    /// Get [`Value`] of `column`
+    #[must_use]
+    pub fn value(&self, column: &str) -> Option<&'a Value> {
+        match self.0 {
+            RowInner::Associative { row, .. } => row.get(column),
+            RowInner::Standard {
+                columns, values, ..
+            } => columns
+                .iter()
+                .position(|c| c == column)
+                .and_then(|index| values.get(index)),
+        }
+    }
+
+    /// Get `column` converted with [`FromSqlValue`]
+    ///
+    /// See [`Row::try_get()`]
+    ///
+    /// # Panics
+    ///
+    /// If `column` is missing, isn't compatible or can't be converted
+    #[must_use]
+    pub fn get<T>(&self, column: &str) -> T
+    where
+        T: FromSqlValue,
+    {
+        self.try_get(column).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Get `column` converted with [`FromSqlValue`] or `None` if missing or `NULL`
+    ///
+    /// # Errors
+    ///
+    /// [`Error::ResultError`] if `column` isn't compatible or can't be converted
+    pub fn get_opt<T>(&self, column: &str) -> Result<Option<T>, Error>
+    where
+        T: FromSqlValue,
+    {
+        match self.value(column) {
+            Some(value) if !value.is_null() => self.convert(column, value).map(Some),
+            _ => Ok(None),
+        }
+    }
+
+    /// Get `column` converted with [`FromSqlValue`]
+    ///
+    /// The [`DataType`] of `column` is validated with [`FromSqlValue::is_compatible()`].
+    ///
+    /// # Errors
+    ///
+    /// [`Error::ResultError`] if `column` is missing, isn't compatible or can't be converted
+    pub fn try_get<T>(&self, column: &str) -> Result<T, Error>
+    where
+        T: FromSqlValue,
+    {
+        match self.value(column) {
+            Some(value) => self.convert(column, value),
+            None => Err(Error::ResultError(format!("no column {column}"))),
+        }
+    }
+
+    /// Convert `value` of `column` after validation of its [`DataType`]
+    fn convert<T>(&self, column: &str, value: &Value) -> Result<T, Error>
+    where
+        T: FromSqlValue,
+    {
+        let data_type = self.data_type(column);
+
+        if let Some(data_type) = data_type {
+            if !T::is_compatible(data_type) {
+                return Err(Error::ResultError(format!(
+                    "column {column}: {data_type} not compatible with {}",
+                    std::any::type_name::<T>()
+                )));
+            }
+        }
+
+        T::from_sql_value(value, data_type)
+            .map_err(|err| Error::ResultError(format!("column {column}: {err}")))
+    }
+}
+
+/// Decode a [`Row`] of a result into `Self`
+///
+/// With feature `derive` there is `#[derive(FromRow)]` for structs with named fields.
+/// Every field is decoded with [`FromSqlValue`] from the column with the name of the field.
+///
+/// The field attribute `#[rqlite(...)]` supports
+///
+/// * `rename = "column"` to decode from another column name
+/// * `default` to use `Default::default()`, if the column is missing or `NULL`
+/// * `default = "path"` to call the function `path`, if the column is missing or `NULL`
+/// * `skip` to always use `Default::default()`
+///
+/// Fields of type `Option<T>` are `None`, if the column is missing or `NULL`.
+///
+/// # Usage
+///
+/// ```no_run
+/// # #[cfg(feature = "derive")]
+/// # {
+/// use rqlite_client::FromRow;
+///
+/// #[derive(FromRow)]
+/// struct Person {
+///     id: i64,
+///     #[rqlite(rename = "name")]
+///     full_name: String,
+///     age: Option<u8>,
+///     #[rqlite(default)]
+///     tags: String,
+/// }
+///
+/// # let response_query: rqlite_client::response::Query = todo!();
+/// let persons = response_query.rows::<Person>()?;
+/// # }
+/// # Ok::<(), rqlite_client::Error>(())
+/// ```
+///
+pub trait FromRow: Sized {
+    /// Decode `row` into `Self`
+    ///
+    /// # Errors
+    ///
+    /// [`Error`] if `row` can't be decoded
+    fn from_row(row: &Row<'_>) -> Result<Self, Error>;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::Row;
+    use crate::{DataType, Value};
+
+    #[test]
+    fn standard_test() {
+        let columns = vec!["id".to_string(), "name".to_string()];
+        let types = vec![DataType::Integer, DataType::Text];
+        let values = vec![Value::from(1), Value::Null];
+        let row = Row::standard(&columns, &types, &values);
+
+        assert_eq!(row.len(), 2);
+        assert_eq!(row.value("id"), Some(&Value::from(1)));
+        assert_eq!(row.data_type("name"), Some(DataType::Text));
+        assert_eq!(row.value("age"), None);
+        assert_eq!(row.get::<i64>("id"), 1);
+        assert!((row.try_get::<f64>("id").unwrap() - 1.0).abs() < f64::EPSILON);
+        assert_eq!(row.get_opt::<String>("name").unwrap(), None);
+        assert_eq!(row.get_opt::<String>("age").unwrap(), None);
+        assert_eq!(
+            row.try_get::<String>("name").unwrap_err().to_string(),
+            "column name: can't convert text value null to String"
+        );
+        assert_eq!(
+            row.try_get::<i64>("age").unwrap_err().to_string(),
+            "no column age"
+        );
+        let err = row.get_opt::<String>("id").unwrap_err().to_string();
+        assert!(
+            err.starts_with("column id: integer not compatible"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn associative_test() {
+        let row = HashMap::from([
+            ("id".to_string(), Value::from(1)),
+            ("name".to_string(), Value::from("fiona")),
+        ]);
+        let types = HashMap::from([
+            ("id".to_string(), DataType::Integer),
+            ("name".to_string(), DataType::Text),
+        ]);
+        let row = Row::associative(&row, &types);
+
+        assert_eq!(row.len(), 2);
+        assert_eq!(row.data_type("id"), Some(DataType::Integer));
+        assert_eq!(row.get::<String>("name"), "fiona");
+        assert!(row.try_get::<i64>("name").is_err());
+    }
+}
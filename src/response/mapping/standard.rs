@@ -2,7 +2,7 @@
 
 use crate::{DataType, Value};
 
-use super::timed::Timed;
+use super::{timed::Timed, Row, StandardDeserializer};
 
 /// `Standard` result
 ///
@@ -117,6 +117,34 @@ impl Standard {
         None
     }
 
+    /// Deserialize all rows into `T` with [`StandardDeserializer`]
+    ///
+    /// # Errors
+    ///
+    /// [`crate::Error::ResultError`] if a row can't be deserialized
+    pub fn deserialize_rows<'de, T>(self) -> Result<Vec<T>, crate::Error>
+    where
+        T: serde::Deserialize<'de>,
+    {
+        <Vec<T> as serde::Deserialize>::deserialize(StandardDeserializer::new(self))
+            .map_err(|err| crate::Error::ResultError(err.to_string()))
+    }
+
+    /// Get [`Row`] view of row `row_index`
+    #[must_use]
+    pub fn row(&self, row_index: usize) -> Option<Row<'_>> {
+        self.values(row_index)
+            .map(|values| Row::standard(&self.columns, &self.types, values))
+    }
+
+    /// Iterator over the [`Row`] views of all rows
+    pub fn row_iter(&self) -> impl Iterator<Item = Row<'_>> {
+        self.values
+            .iter()
+            .flatten()
+            .map(|values| Row::standard(&self.columns, &self.types, values))
+    }
+
     /// Get values of row `row_index`
     #[must_use]
     pub fn values(&self, row_index: usize) -> Option<&Vec<Value>> {
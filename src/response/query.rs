@@ -2,8 +2,8 @@
 
 use std::time::Duration;
 
-use super::mapping::Mapping;
-use crate::Error;
+use super::mapping::{self, FromRow, Mapping};
+use crate::{Error, Value};
 
 /// Result type with [`response::query::Query`](crate::response::query::Query) or [`Error`]
 pub type Result = std::result::Result<Query, Error>;
@@ -13,7 +13,7 @@ pub type Result = std::result::Result<Query, Error>;
 /// See [`Mapping`] for available variants of `results` and
 /// [examples](../mapping/enum.Mapping.html#examples) to handle responses.
 ///
-#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct Query {
     /// `raft_index` in raft index log
     ///
@@ -40,6 +40,24 @@ impl Query {
         self.time.map(Duration::from_secs_f64)
     }
 
+    /// Get the first [`mapping::Error`] with the index and the statement of `sql`
+    ///
+    /// `sql` are the statements of the requested [`Query`](crate::Query), see
+    /// [`Query::sql()`](crate::Query::sql) and [`Query::statement_results()`].
+    ///
+    #[must_use]
+    pub fn first_error<'a>(
+        &'a self,
+        sql: &'a [Value],
+    ) -> Option<(usize, &'a str, &'a mapping::Error)> {
+        self.statement_results(sql)
+            .enumerate()
+            .find_map(|(index, (statement, _, result))| match result {
+                Mapping::Error(err) => Some((index, statement, err)),
+                _ => None,
+            })
+    }
+
     /// Iterator for available [`Result`]s
     pub fn iter(&self) -> std::slice::Iter<'_, Mapping> {
         self.results.iter()
@@ -54,11 +72,67 @@ impl Query {
         self.raft_index
     }
 
+    /// Decode the rows of all results with [`FromRow`]
+    ///
+    /// # Errors
+    ///
+    /// [`Error::ResultError`] for the first [`Mapping::Error`] or if a row can't be decoded
+    pub fn rows<T>(&self) -> std::result::Result<Vec<T>, Error>
+    where
+        T: FromRow,
+    {
+        let mut rows = Vec::new();
+        for mapping in &self.results {
+            rows.append(&mut mapping.rows()?);
+        }
+        Ok(rows)
+    }
+
     /// Iterator for available [`Result`]s
     pub fn results(&self) -> std::slice::Iter<'_, Mapping> {
         self.results.iter()
     }
 
+    /// Iterator over the statements of `sql` with their parameters and [`Mapping`] result
+    ///
+    /// `sql` are the statements of the requested [`Query`](crate::Query), see
+    /// [`Query::sql()`](crate::Query::sql).
+    /// The results are in the same order as the statements. If there are fewer results,
+    /// e.g. of a failed transaction, the iterator ends with the last result.
+    ///
+    /// ```
+    /// use rqlite_client::{response, Mapping};
+    ///
+    /// let sql = vec![
+    ///     serde_json::json!(["INSERT INTO foo(name) VALUES(?)", "fiona"]),
+    ///     serde_json::json!("SELECT * FROM bar"),
+    /// ];
+    /// let query: response::Query = serde_json::from_str(
+    ///     r#"{"results":[{"last_insert_id":1,"rows_affected":1},{"error":"no such table: bar"}]}"#,
+    /// )?;
+    ///
+    /// let mut results = query.statement_results(&sql);
+    /// let (statement, parameters, result) = results.next().unwrap();
+    /// assert_eq!(statement, "INSERT INTO foo(name) VALUES(?)");
+    /// assert_eq!(parameters, &[serde_json::json!("fiona")]);
+    /// assert!(matches!(result, Mapping::Execute(_)));
+    ///
+    /// let (index, statement, err) = query.first_error(&sql).unwrap();
+    /// assert_eq!((index, statement), (1, "SELECT * FROM bar"));
+    /// assert_eq!(err.error, "no such table: bar");
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    ///
+    pub fn statement_results<'a>(
+        &'a self,
+        sql: &'a [Value],
+    ) -> impl Iterator<Item = (&'a str, &'a [Value], &'a Mapping)> {
+        sql.iter()
+            .map(split_statement)
+            .zip(&self.results)
+            .map(|((statement, parameters), result)| (statement, parameters, result))
+    }
+
     /// For queued writes there will be a `sequence_number`
     #[must_use]
     #[inline]
@@ -99,6 +173,24 @@ impl<'a> IntoIterator for &'a Query {
     }
 }
 
+#[cfg(feature = "reqwest")]
+impl TryFrom<reqwest::blocking::Response> for Query {
+    type Error = Error;
+
+    fn try_from(response: reqwest::blocking::Response) -> std::result::Result<Self, Self::Error> {
+        let status = response.status();
+
+        if !status.is_success() {
+            return Err(Error::HttpError(
+                status.as_u16(),
+                status.canonical_reason().unwrap_or_default().to_string(),
+            ));
+        }
+
+        response.json::<Self>().map_err(Error::from)
+    }
+}
+
 #[cfg(feature = "ureq")]
 impl TryFrom<ureq::Response> for Query {
     type Error = Error;
@@ -114,13 +206,28 @@ impl TryFrom<ureq::Response> for Query {
     }
 }
 
+/// Split `sql` of a single statement into the statement and its parameters
+///
+/// The statement is a string or an array of the statement followed by the parameters.
+///
+fn split_statement(sql: &Value) -> (&str, &[Value]) {
+    match sql {
+        Value::String(statement) => (statement, &[]),
+        Value::Array(array) => match array.split_first() {
+            Some((statement, parameters)) => (statement.as_str().unwrap_or_default(), parameters),
+            None => ("", &[]),
+        },
+        _ => ("", &[]),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::{collections::HashMap, time::Duration};
 
     use super::Query;
     use crate::response::mapping::{Associative, Mapping, Standard};
-    use crate::DataType;
+    use crate::{DataType, Value};
 
     #[test]
     fn response_associative_json_test() {
@@ -389,4 +496,39 @@ mod tests {
             _ => unreachable!(),
         }
     }
+
+    #[test]
+    fn statement_results_test() {
+        let sql = vec![
+            Value::from("CREATE TABLE foo (id INTEGER PRIMARY KEY, name TEXT)"),
+            serde_json::json!(["INSERT INTO foo(name) VALUES(:name)", {"name": "fiona"}]),
+            serde_json::json!(["INSERT INTO foo(name) VALUES(?)"]),
+            serde_json::json!(["SELECT * FROM foo"]),
+        ];
+        let json = r#"{
+            "results": [
+                {},
+                {"last_insert_id": 1, "rows_affected": 1},
+                {"error": "NOT NULL constraint failed: foo.name"}
+            ]
+        }"#;
+        let r: Query = serde_json::from_str(json).unwrap();
+
+        let results = r.statement_results(&sql).collect::<Vec<_>>();
+        assert_eq!(results.len(), 3);
+        assert_eq!(
+            results[0].0,
+            "CREATE TABLE foo (id INTEGER PRIMARY KEY, name TEXT)"
+        );
+        assert!(results[0].1.is_empty());
+        assert_eq!(results[1].1, &[serde_json::json!({"name": "fiona"})]);
+        assert!(matches!(results[1].2, Mapping::Execute(_)));
+
+        let (index, statement, err) = r.first_error(&sql).unwrap();
+        assert_eq!(index, 2);
+        assert_eq!(statement, "INSERT INTO foo(name) VALUES(?)");
+        assert_eq!(err.error, "NOT NULL constraint failed: foo.name");
+
+        assert!(r.first_error(&sql[..2]).is_none());
+    }
 }
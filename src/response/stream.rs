@@ -0,0 +1,511 @@
+//! Streaming of the rows of a [`Query`](super::Query) response with [`RowStream`]
+
+use std::io::{BufRead, BufReader, Read};
+
+use crate::{DataType, Error, Value};
+
+use super::mapping::Row;
+#[cfg(any(feature = "reqwest", feature = "ureq"))]
+use super::Content;
+
+/// Iterator over the rows of a streamed response of
+/// [`Query::request_stream()`](crate::Query::request_stream)
+///
+/// The JSON response body is parsed incrementally while iterating and only a single row
+/// is held in memory. The rows of all results are yielded in order of the results.
+///
+/// The `columns` and `types` of the current result are available with [`RowStream::columns()`]
+/// and [`RowStream::types()`]. A [`Row`] view of the values is created with [`RowStream::row()`].
+///
+/// Only the standard result form is supported. An error result is yielded as
+/// [`Error::ResultError`], but the stream continues with the next result.
+///
+/// # Usage
+///
+/// ```no_run
+/// use rqlite_client::{Connection, FromRow};
+///
+/// # #[derive(Debug)]
+/// # struct Person;
+/// # impl FromRow for Person {
+/// #     fn from_row(_row: &rqlite_client::response::mapping::Row<'_>) -> Result<Self, rqlite_client::Error> { Ok(Person) }
+/// # }
+/// # #[cfg(feature = "url")]
+/// let con = Connection::new("http://localhost:4001")?;
+/// # #[cfg(not(feature = "url"))]
+/// # let con = Connection::new("http://localhost:4001");
+///
+/// let mut stream = con.query().set_sql_str("SELECT * FROM person").request_stream()?;
+///
+/// while let Some(values) = stream.next() {
+///     let person = Person::from_row(&stream.row(&values?))?;
+///     println!("{person:?}");
+/// }
+/// # Ok::<(), rqlite_client::Error>(())
+/// ```
+///
+pub struct RowStream {
+    buf: Vec<u8>,
+    columns: Vec<String>,
+    reader: Box<dyn BufRead + Send>,
+    result_index: Option<usize>,
+    state: StreamState,
+    types: Vec<DataType>,
+}
+
+/// Position of the parser in the JSON response body
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum StreamState {
+    /// Before the response object
+    Start,
+    /// In the response object, `is_first` member or not
+    Response { is_first: bool },
+    /// In the `results` array
+    Results { is_first: bool },
+    /// In a result object
+    Result { is_first: bool },
+    /// In the `values` array of a result
+    Values { is_first: bool },
+    /// End of the response or failed
+    Done,
+}
+
+impl RowStream {
+    /// Create `RowStream` parsing the JSON response body of `reader`
+    pub fn from_reader<R>(reader: R) -> Self
+    where
+        R: Read + Send + 'static,
+    {
+        Self {
+            buf: Vec::new(),
+            columns: Vec::new(),
+            reader: Box::new(BufReader::new(reader)),
+            result_index: None,
+            state: StreamState::Start,
+            types: Vec::new(),
+        }
+    }
+
+    /// Columns of the current result
+    #[must_use]
+    pub fn columns(&self) -> &[String] {
+        &self.columns
+    }
+
+    /// Index of the current result in the response, `None` before the first result
+    #[must_use]
+    pub fn result_index(&self) -> Option<usize> {
+        self.result_index
+    }
+
+    /// [`Row`] view of `values` of the current result
+    #[must_use]
+    pub fn row<'a>(&'a self, values: &'a [Value]) -> Row<'a> {
+        Row::standard(&self.columns, &self.types, values)
+    }
+
+    /// [`DataType`]s of the columns of the current result
+    #[must_use]
+    pub fn types(&self) -> &[DataType] {
+        &self.types
+    }
+
+    /// Parse until the next row or error result
+    ///
+    /// An error result is returned as _inner_ error, the _outer_ error is an invalid response.
+    ///
+    fn advance(&mut self) -> Result<Option<Result<Vec<Value>, Error>>, Error> {
+        loop {
+            match self.state {
+                StreamState::Start => {
+                    self.expect(b'{')?;
+                    self.state = StreamState::Response { is_first: true };
+                }
+                StreamState::Response { is_first } => {
+                    self.state = StreamState::Response { is_first: false };
+
+                    match self.next_key(is_first, b'}')?.as_deref() {
+                        Some("results") => {
+                            self.expect(b'[')?;
+                            self.state = StreamState::Results { is_first: true };
+                        }
+                        Some(_) => self.skip_value()?,
+                        None => self.state = StreamState::Done,
+                    }
+                }
+                StreamState::Results { is_first } => {
+                    if self.next_element(is_first, b']')? {
+                        self.expect(b'{')?;
+                        self.columns.clear();
+                        self.types.clear();
+                        self.result_index = Some(self.result_index.map_or(0, |i| i + 1));
+                        self.state = StreamState::Result { is_first: true };
+                    } else {
+                        self.state = StreamState::Response { is_first: false };
+                    }
+                }
+                StreamState::Result { is_first } => {
+                    self.state = StreamState::Result { is_first: false };
+
+                    match self.next_key(is_first, b'}')?.as_deref() {
+                        Some("columns") => self.columns = self.read_value()?,
+                        Some("rows") => return Err(associative_unsupported()),
+                        Some("types") if self.peek()? == Some(b'{') => {
+                            return Err(associative_unsupported());
+                        }
+                        Some("types") => self.types = self.read_value()?,
+                        Some("values") => {
+                            if self.peek()? == Some(b'[') {
+                                self.expect(b'[')?;
+                                self.state = StreamState::Values { is_first: true };
+                            } else {
+                                self.skip_value()?;
+                            }
+                        }
+                        Some("error") => {
+                            return Ok(Some(Err(Error::ResultError(self.read_value()?))));
+                        }
+                        Some(_) => self.skip_value()?,
+                        None => self.state = StreamState::Results { is_first: false },
+                    }
+                }
+                StreamState::Values { is_first } => {
+                    if self.next_element(is_first, b']')? {
+                        self.state = StreamState::Values { is_first: false };
+                        return self.read_value().map(|values| Some(Ok(values)));
+                    }
+                    self.state = StreamState::Result { is_first: false };
+                }
+                StreamState::Done => return Ok(None),
+            }
+        }
+    }
+
+    /// Consume the non-whitespace byte `expected`
+    fn expect(&mut self, expected: u8) -> Result<(), Error> {
+        match self.peek()? {
+            Some(b) if b == expected => {
+                self.reader.consume(1);
+                Ok(())
+            }
+            Some(b) => Err(Error::ResultError(format!(
+                "invalid response: expected '{}', found '{}'",
+                char::from(expected),
+                char::from(b)
+            ))),
+            None => Err(unexpected_eof()),
+        }
+    }
+
+    /// Check for a next element of an array or object ending with `close`
+    ///
+    /// Consumes the separating `,` or the closing byte.
+    ///
+    fn next_element(&mut self, is_first: bool, close: u8) -> Result<bool, Error> {
+        if self.peek()? == Some(close) {
+            self.reader.consume(1);
+            return Ok(false);
+        }
+        if !is_first {
+            self.expect(b',')?;
+        }
+        Ok(true)
+    }
+
+    /// Read the key of the next member of an object including the `:`
+    fn next_key(&mut self, is_first: bool, close: u8) -> Result<Option<String>, Error> {
+        if !self.next_element(is_first, close)? {
+            return Ok(None);
+        }
+        let key = self.read_value::<String>()?;
+        self.expect(b':')?;
+        Ok(Some(key))
+    }
+
+    /// Peek the next non-whitespace byte
+    fn peek(&mut self) -> Result<Option<u8>, Error> {
+        loop {
+            let buf = self.reader.fill_buf()?;
+            let Some(&b) = buf.first() else {
+                return Ok(None);
+            };
+            if b.is_ascii_whitespace() {
+                self.reader.consume(1);
+            } else {
+                return Ok(Some(b));
+            }
+        }
+    }
+
+    /// Read the next JSON value and decode it into `T`
+    fn read_value<T>(&mut self) -> Result<T, Error>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        self.buf.clear();
+        self.scan_value(true)?;
+        serde_json::from_slice(&self.buf).map_err(Error::from)
+    }
+
+    /// Skip the next JSON value
+    fn skip_value(&mut self) -> Result<(), Error> {
+        self.scan_value(false)
+    }
+
+    /// Scan the next JSON value and append its bytes to `buf`, if `is_keep`
+    ///
+    /// Scalars end before the next `,`, `]`, `}` or whitespace, which isn't consumed.
+    ///
+    fn scan_value(&mut self, is_keep: bool) -> Result<(), Error> {
+        let Some(first) = self.peek()? else {
+            return Err(unexpected_eof());
+        };
+        let is_scalar = !matches!(first, b'"' | b'[' | b'{');
+        let mut depth = 0_usize;
+        let mut is_string = false;
+        let mut is_escape = false;
+
+        loop {
+            let buf = self.reader.fill_buf()?;
+            if buf.is_empty() {
+                return if is_scalar && depth == 0 {
+                    Ok(())
+                } else {
+                    Err(unexpected_eof())
+                };
+            }
+
+            let mut end = None;
+            for (i, &b) in buf.iter().enumerate() {
+                if is_scalar {
+                    if matches!(b, b',' | b']' | b'}') || b.is_ascii_whitespace() {
+                        end = Some(i);
+                        break;
+                    }
+                } else if is_string {
+                    if is_escape {
+                        is_escape = false;
+                    } else if b == b'\\' {
+                        is_escape = true;
+                    } else if b == b'"' {
+                        is_string = false;
+                        if depth == 0 {
+                            end = Some(i + 1);
+                            break;
+                        }
+                    }
+                } else {
+                    match b {
+                        b'"' => is_string = true,
+                        b'[' | b'{' => depth += 1,
+                        b']' | b'}' => {
+                            depth -= 1;
+                            if depth == 0 {
+                                end = Some(i + 1);
+                                break;
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+
+            let len = end.unwrap_or(buf.len());
+            if is_keep {
+                self.buf.extend_from_slice(&buf[..len]);
+            }
+            self.reader.consume(len);
+
+            if end.is_some() {
+                return Ok(());
+            }
+        }
+    }
+}
+
+impl Iterator for RowStream {
+    type Item = Result<Vec<Value>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.advance() {
+            Ok(item) => item,
+            Err(err) => {
+                self.state = StreamState::Done;
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+#[cfg(feature = "reqwest")]
+impl TryFrom<reqwest::blocking::Response> for RowStream {
+    type Error = Error;
+
+    fn try_from(response: reqwest::blocking::Response) -> Result<Self, Self::Error> {
+        let status = response.status();
+
+        if !status.is_success() {
+            return Err(Error::HttpError(
+                status.as_u16(),
+                status.canonical_reason().unwrap_or_default().to_string(),
+            ));
+        }
+
+        let content = Content::detect(
+            response
+                .headers()
+                .get(reqwest::header::CONTENT_TYPE)
+                .and_then(|v| v.to_str().ok()),
+            response
+                .content_length()
+                .and_then(|l| usize::try_from(l).ok()),
+        )?;
+
+        match content {
+            Content::Json => Ok(Self::from_reader(response)),
+            Content::Text => Err("unsupported response".into()),
+        }
+    }
+}
+
+#[cfg(feature = "ureq")]
+impl TryFrom<ureq::Response> for RowStream {
+    type Error = Error;
+
+    fn try_from(response: ureq::Response) -> Result<Self, Self::Error> {
+        let status = response.status();
+
+        if !(200..300).contains(&status) {
+            return Err(Error::HttpError(status, response.status_text().to_string()));
+        }
+
+        let content = Content::detect(
+            response.header("Content-Type"),
+            response
+                .header("Content-Length")
+                .and_then(|s| s.parse::<usize>().ok()),
+        )?;
+
+        match content {
+            Content::Json => Ok(Self::from_reader(response.into_reader())),
+            Content::Text => Err("unsupported response".into()),
+        }
+    }
+}
+
+impl std::fmt::Debug for RowStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RowStream")
+            .field("columns", &self.columns)
+            .field("result_index", &self.result_index)
+            .field("types", &self.types)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Error for an associative result
+fn associative_unsupported() -> Error {
+    Error::ResultError("associative result not supported for streaming".to_string())
+}
+
+/// Error for an unexpected end of the response body
+fn unexpected_eof() -> Error {
+    Error::ResultError("invalid response: unexpected end".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RowStream;
+    use crate::{DataType, Value};
+
+    #[test]
+    fn standard_test() {
+        let body = br#"{
+            "results": [
+                {
+                    "columns": ["id", "name"],
+                    "types": ["integer", "text"],
+                    "values": [
+                        [1, "fiona"],
+                        [2, "a \"quoted\" [name]\\"]
+                    ],
+                    "time": 0.0150043
+                },
+                {
+                    "last_insert_id": 1,
+                    "rows_affected": 1
+                },
+                {
+                    "columns": ["blob"],
+                    "types": ["blob"],
+                    "values": [[null]]
+                }
+            ],
+            "time": 0.0220043
+        }"#;
+        let mut stream = RowStream::from_reader(&body[..]);
+
+        assert_eq!(stream.result_index(), None);
+        assert_eq!(
+            stream.next().unwrap().unwrap(),
+            vec![Value::from(1), Value::from("fiona")]
+        );
+        assert_eq!(stream.columns(), ["id", "name"]);
+        assert_eq!(stream.types(), [DataType::Integer, DataType::Text]);
+        assert_eq!(stream.result_index(), Some(0));
+
+        let values = stream.next().unwrap().unwrap();
+        assert_eq!(
+            stream.row(&values).get::<String>("name"),
+            "a \"quoted\" [name]\\"
+        );
+
+        assert_eq!(stream.next().unwrap().unwrap(), vec![Value::Null]);
+        assert_eq!(stream.columns(), ["blob"]);
+        assert_eq!(stream.result_index(), Some(2));
+        assert!(stream.next().is_none());
+        assert!(stream.next().is_none());
+    }
+
+    #[test]
+    fn error_result_test() {
+        let body = br#"{"results":[{"error":"no such table: foo"},{"columns":["id"],"types":["integer"],"values":[[1]]},{"columns":["id"],"types":["integer"]}],"time":1}"#;
+        let mut stream = RowStream::from_reader(&body[..]);
+
+        assert_eq!(
+            stream.next().unwrap().unwrap_err().to_string(),
+            "no such table: foo"
+        );
+        assert_eq!(stream.next().unwrap().unwrap(), vec![Value::from(1)]);
+        assert!(stream.next().is_none());
+    }
+
+    #[test]
+    fn invalid_response_test() {
+        let body = br#"{"results":[{"columns":["id"],"values":[[1],[2"#;
+        let mut stream = RowStream::from_reader(&body[..]);
+
+        assert_eq!(stream.next().unwrap().unwrap(), vec![Value::from(1)]);
+        assert_eq!(
+            stream.next().unwrap().unwrap_err().to_string(),
+            "invalid response: unexpected end"
+        );
+        assert!(stream.next().is_none());
+
+        let body = br#"{"results":[{"types":{"id":"integer"},"rows":[{"id":1}]}]}"#;
+        let mut stream = RowStream::from_reader(&body[..]);
+
+        assert_eq!(
+            stream.next().unwrap().unwrap_err().to_string(),
+            "associative result not supported for streaming"
+        );
+        assert!(stream.next().is_none());
+
+        let mut stream = RowStream::from_reader(&b"[]"[..]);
+
+        assert_eq!(
+            stream.next().unwrap().unwrap_err().to_string(),
+            "invalid response: expected '{', found '['"
+        );
+    }
+}
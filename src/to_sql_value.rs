@@ -0,0 +1,413 @@
+//! Conversion of parameters with [`ToSqlValue`]
+
+use std::borrow::Cow;
+
+use crate::Value;
+
+/// Conversion of a parameter of a parameterized statement into a [`Value`]
+///
+/// [`ToSqlValue`] is accepted by `push_sql_values()`, `set_sql_values()` and `push_sql_named()` of
+/// [`Query`](crate::Query) and by the [`crate::varparam!`] and [`crate::namedparam!`] macros.
+///
+/// | Rust type | SQL value |
+/// |---|---|
+/// | `bool` | boolean |
+/// | integers | integer |
+/// | `f32`, `f64` | real, `NULL` if not finite |
+/// | `char`, `str`, `String`, `Cow<str>` | text |
+/// | `[T]`, `[T; N]`, `Vec<T>` | array, e.g. blob as byte array for `u8` |
+/// | `Option<T>` | `NULL` for `None` |
+/// | `()` | `NULL` |
+/// | [`Value`], `serde_json::Number`, `serde_json::Map` | unchanged JSON value |
+/// | `time::Date`, `chrono::NaiveDate` | text `YYYY-MM-DD` |
+/// | `time::Time`, `chrono::NaiveTime` | text `HH:MM:SS.SSS` |
+/// | `time::PrimitiveDateTime`, `chrono::NaiveDateTime` | text `YYYY-MM-DD HH:MM:SS.SSS` |
+/// | `time::OffsetDateTime`, `chrono::DateTime<Tz>` | text `YYYY-MM-DD HH:MM:SS.SSS` in UTC |
+/// | `uuid::Uuid` | text in hyphenated form |
+///
+/// The date and time formats are the ones of the
+/// [SQLite date and time functions](https://www.sqlite.org/lang_datefunc.html).
+/// The fractional seconds are only added, if there are milliseconds.
+///
+/// The conversions of `time`, `chrono` and `uuid` types require the features with the same name.
+///
+/// # Usage
+///
+/// Implement [`ToSqlValue`] for a custom newtype
+///
+/// ```no_run
+/// use rqlite_client::{varparam, ToSqlValue, Value};
+///
+/// struct UserId(u32);
+///
+/// impl ToSqlValue for UserId {
+///     fn to_sql_value(&self) -> Value {
+///         self.0.to_sql_value()
+///     }
+/// }
+///
+/// let stmt = varparam!["SELECT * FROM user WHERE id = ? AND nick = ?", UserId(1), None::<&str>];
+/// ```
+///
+pub trait ToSqlValue {
+    /// Convert `self` into [`Value`] of a parameter
+    fn to_sql_value(&self) -> Value;
+}
+
+impl<T> ToSqlValue for &T
+where
+    T: ToSqlValue + ?Sized,
+{
+    #[inline]
+    fn to_sql_value(&self) -> Value {
+        (**self).to_sql_value()
+    }
+}
+
+impl<T> ToSqlValue for Option<T>
+where
+    T: ToSqlValue,
+{
+    #[inline]
+    fn to_sql_value(&self) -> Value {
+        self.as_ref().map_or(Value::Null, ToSqlValue::to_sql_value)
+    }
+}
+
+impl ToSqlValue for Value {
+    #[inline]
+    fn to_sql_value(&self) -> Value {
+        self.clone()
+    }
+}
+
+impl ToSqlValue for char {
+    #[inline]
+    fn to_sql_value(&self) -> Value {
+        Value::String(self.to_string())
+    }
+}
+
+impl ToSqlValue for str {
+    #[inline]
+    fn to_sql_value(&self) -> Value {
+        Value::String(self.to_string())
+    }
+}
+
+impl ToSqlValue for String {
+    #[inline]
+    fn to_sql_value(&self) -> Value {
+        Value::String(self.clone())
+    }
+}
+
+impl ToSqlValue for Cow<'_, str> {
+    #[inline]
+    fn to_sql_value(&self) -> Value {
+        Value::String(self.to_string())
+    }
+}
+
+impl<T> ToSqlValue for [T]
+where
+    T: ToSqlValue,
+{
+    #[inline]
+    fn to_sql_value(&self) -> Value {
+        Value::Array(self.iter().map(ToSqlValue::to_sql_value).collect())
+    }
+}
+
+impl<T, const N: usize> ToSqlValue for [T; N]
+where
+    T: ToSqlValue,
+{
+    #[inline]
+    fn to_sql_value(&self) -> Value {
+        self.as_slice().to_sql_value()
+    }
+}
+
+impl<T> ToSqlValue for Vec<T>
+where
+    T: ToSqlValue,
+{
+    #[inline]
+    fn to_sql_value(&self) -> Value {
+        self.as_slice().to_sql_value()
+    }
+}
+
+impl ToSqlValue for () {
+    #[inline]
+    fn to_sql_value(&self) -> Value {
+        Value::Null
+    }
+}
+
+impl ToSqlValue for serde_json::Number {
+    #[inline]
+    fn to_sql_value(&self) -> Value {
+        Value::Number(self.clone())
+    }
+}
+
+impl ToSqlValue for serde_json::Map<String, Value> {
+    #[inline]
+    fn to_sql_value(&self) -> Value {
+        Value::Object(self.clone())
+    }
+}
+
+/// Generate [`ToSqlValue`] impls for types with `From<T> for Value`
+macro_rules! gen_to_sql_value {
+    ( $($t:ty),+ ) => {
+        $(
+            impl ToSqlValue for $t {
+                #[inline]
+                fn to_sql_value(&self) -> Value {
+                    Value::from(*self)
+                }
+            }
+        )+
+    };
+}
+
+gen_to_sql_value!(bool, f32, f64, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+#[cfg(feature = "time")]
+impl ToSqlValue for time::Date {
+    fn to_sql_value(&self) -> Value {
+        Value::String(date_text(self.year(), u8::from(self.month()), self.day()))
+    }
+}
+
+#[cfg(feature = "time")]
+impl ToSqlValue for time::Time {
+    fn to_sql_value(&self) -> Value {
+        Value::String(time_text(
+            self.hour(),
+            self.minute(),
+            self.second(),
+            self.millisecond(),
+        ))
+    }
+}
+
+#[cfg(feature = "time")]
+impl ToSqlValue for time::PrimitiveDateTime {
+    fn to_sql_value(&self) -> Value {
+        Value::String(format!(
+            "{} {}",
+            date_text(self.year(), u8::from(self.month()), self.day()),
+            time_text(
+                self.hour(),
+                self.minute(),
+                self.second(),
+                self.millisecond()
+            )
+        ))
+    }
+}
+
+#[cfg(feature = "time")]
+impl ToSqlValue for time::OffsetDateTime {
+    fn to_sql_value(&self) -> Value {
+        let utc = self.to_offset(time::UtcOffset::UTC);
+        time::PrimitiveDateTime::new(utc.date(), utc.time()).to_sql_value()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl ToSqlValue for chrono::NaiveDate {
+    fn to_sql_value(&self) -> Value {
+        Value::String(chrono_date_text(*self))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl ToSqlValue for chrono::NaiveTime {
+    fn to_sql_value(&self) -> Value {
+        Value::String(chrono_time_text(*self))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl ToSqlValue for chrono::NaiveDateTime {
+    fn to_sql_value(&self) -> Value {
+        Value::String(format!(
+            "{} {}",
+            chrono_date_text(self.date()),
+            chrono_time_text(self.time())
+        ))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz> ToSqlValue for chrono::DateTime<Tz>
+where
+    Tz: chrono::TimeZone,
+{
+    fn to_sql_value(&self) -> Value {
+        self.naive_utc().to_sql_value()
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl ToSqlValue for uuid::Uuid {
+    fn to_sql_value(&self) -> Value {
+        Value::String(self.hyphenated().to_string())
+    }
+}
+
+/// Date of `chrono` in format `YYYY-MM-DD`
+#[cfg(feature = "chrono")]
+#[allow(clippy::cast_possible_truncation)]
+fn chrono_date_text(date: chrono::NaiveDate) -> String {
+    use chrono::Datelike;
+
+    date_text(date.year(), date.month() as u8, date.day() as u8)
+}
+
+/// Time of `chrono` in format `HH:MM:SS` or `HH:MM:SS.SSS`
+///
+/// A leap second is represented with nanoseconds >= `1_000_000_000`.
+///
+#[cfg(feature = "chrono")]
+#[allow(clippy::cast_possible_truncation)]
+fn chrono_time_text(time: chrono::NaiveTime) -> String {
+    use chrono::Timelike;
+
+    time_text(
+        time.hour() as u8,
+        time.minute() as u8,
+        time.second() as u8,
+        (time.nanosecond() % 1_000_000_000 / 1_000_000) as u16,
+    )
+}
+
+/// Date in format `YYYY-MM-DD`
+#[cfg(any(feature = "chrono", feature = "time"))]
+fn date_text(year: i32, month: u8, day: u8) -> String {
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+/// Time in format `HH:MM:SS` or `HH:MM:SS.SSS` with milliseconds
+#[cfg(any(feature = "chrono", feature = "time"))]
+fn time_text(hour: u8, minute: u8, second: u8, millisecond: u16) -> String {
+    if millisecond == 0 {
+        format!("{hour:02}:{minute:02}:{second:02}")
+    } else {
+        format!("{hour:02}:{minute:02}:{second:02}.{millisecond:03}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ToSqlValue;
+    use crate::Value;
+
+    #[test]
+    fn std_test() {
+        assert_eq!(true.to_sql_value(), Value::Bool(true));
+        assert_eq!(42_u8.to_sql_value(), Value::from(42));
+        assert_eq!((-42_i64).to_sql_value(), Value::from(-42));
+        assert_eq!(u64::MAX.to_sql_value(), Value::from(u64::MAX));
+        assert_eq!(1.5_f64.to_sql_value(), Value::from(1.5));
+        assert_eq!(f64::NAN.to_sql_value(), Value::Null);
+        assert_eq!('x'.to_sql_value(), Value::from("x"));
+        assert_eq!("text".to_sql_value(), Value::from("text"));
+        assert_eq!(String::from("text").to_sql_value(), Value::from("text"));
+        assert_eq!((&&"text").to_sql_value(), Value::from("text"));
+        assert_eq!(Value::from(1).to_sql_value(), Value::from(1));
+    }
+
+    #[test]
+    fn option_test() {
+        assert_eq!(Some(1).to_sql_value(), Value::from(1));
+        assert_eq!(None::<i32>.to_sql_value(), Value::Null);
+        assert_eq!(Some("text").to_sql_value(), Value::from("text"));
+    }
+
+    #[test]
+    fn bytes_test() {
+        assert_eq!(
+            b"\x00\x01\xff"[..].to_sql_value(),
+            Value::from(vec![0, 1, 255])
+        );
+        assert_eq!(vec![1_u8, 2].to_sql_value(), Value::from(vec![1, 2]));
+        assert_eq!([1_u8, 2].to_sql_value(), Value::from(vec![1, 2]));
+    }
+
+    #[test]
+    fn json_test() {
+        use std::borrow::Cow;
+
+        use serde_json::{json, Map, Number};
+
+        assert_eq!(Number::from(7).to_sql_value(), json!(7));
+        assert_eq!(Map::new().to_sql_value(), json!({}));
+        assert_eq!(().to_sql_value(), Value::Null);
+        assert_eq!(Cow::from("text").to_sql_value(), json!("text"));
+        assert_eq!(vec![json!(1), json!("a")].to_sql_value(), json!([1, "a"]));
+    }
+
+    #[test]
+    fn query_values_test() {
+        use std::borrow::Cow;
+
+        use serde_json::{json, Map, Number};
+
+        let c = crate::Connection::default();
+
+        let q = c
+            .execute()
+            .push_sql_values(&[json!("INSERT INTO tbl (a) VALUES (?)"), json!(1)])
+            .push_sql_values(&[Cow::from("INSERT INTO tbl (a) VALUES (?)"), Cow::from("b")]);
+        assert_eq!(
+            q.sql(),
+            &vec![
+                json!(["INSERT INTO tbl (a) VALUES (?)", 1]),
+                json!(["INSERT INTO tbl (a) VALUES (?)", "b"])
+            ]
+        );
+
+        let q = c.query().set_sql_values(&[Number::from(1)]);
+        assert_eq!(q.sql(), &vec![json!([1])]);
+
+        let q = c.query().set_sql_values(&[Map::new()]);
+        assert_eq!(q.sql(), &vec![json!([{}])]);
+
+        let q = c.query().set_sql_values(&[(), ()]);
+        assert_eq!(q.sql(), &vec![json!([null, null])]);
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time_test() {
+        use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+        let date = Date::from_calendar_date(2024, Month::February, 29).unwrap();
+        let time = Time::from_hms(7, 8, 9).unwrap();
+        let time_milli = Time::from_hms_micro(1, 8, 9, 500_456).unwrap();
+
+        assert_eq!(date.to_sql_value(), Value::from("2024-02-29"));
+        assert_eq!(time.to_sql_value(), Value::from("07:08:09"));
+        assert_eq!(time_milli.to_sql_value(), Value::from("01:08:09.500"));
+        assert_eq!(
+            PrimitiveDateTime::new(date, time).to_sql_value(),
+            Value::from("2024-02-29 07:08:09")
+        );
+        assert_eq!(
+            PrimitiveDateTime::new(date, time_milli)
+                .assume_offset(UtcOffset::from_hms(2, 0, 0).unwrap())
+                .to_sql_value(),
+            Value::from("2024-02-28 23:08:09.500")
+        );
+        assert_eq!(
+            OffsetDateTime::UNIX_EPOCH.to_sql_value(),
+            Value::from("1970-01-01 00:00:00")
+        );
+    }
+}
@@ -1,7 +1,7 @@
 #!/bin/sh
 
-features="migration migration_embed ureq ureq_tls ureq_webpki"
-features_addon="log monitor percent_encoding tracing ureq_charset ureq_socks_proxy url"
+features="migration migration_embed reqwest reqwest_tls reqwest_webpki ureq ureq_tls ureq_webpki"
+features_addon="chrono derive log monitor percent_encoding rustls time tokio tracing ureq_charset ureq_socks_proxy url uuid"
 
 
 cargo_test() {
@@ -0,0 +1,109 @@
+#![allow(missing_docs, unused_crate_dependencies)]
+#![cfg(feature = "tokio")]
+
+use std::sync::Arc;
+
+use test_rqlited::TestRqlited;
+
+use rqlite_client::{
+    request_type::{Get, Post},
+    response::{
+        self,
+        mapping::{self, Mapping},
+    },
+    AsyncRequestBuilder, Connection, DataType, Query, Request,
+};
+
+fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap()
+        .block_on(future)
+}
+
+#[test]
+fn nolevel_async_test() {
+    TestRqlited::get_or_init().run_test(|c: Connection| {
+        let r = block_on(Request::from(Get).run_async(&c.query().set_sql_str("SELECT 1")));
+
+        assert!(r.is_ok(), "response error: {}", r.err().unwrap());
+
+        let r = response::query::Query::try_from(r.unwrap()).unwrap();
+        let result = r.results().next().unwrap();
+
+        match result {
+            Mapping::Standard(result) => {
+                assert_eq!(
+                    result,
+                    &mapping::Standard {
+                        columns: vec!["1".to_string()],
+                        time: None,
+                        types: vec![DataType::Integer],
+                        values: Some(vec![vec![1.into()]])
+                    }
+                );
+            }
+            _ => unreachable!(),
+        }
+    });
+}
+
+#[test]
+fn nolevel_multi_async_test() {
+    TestRqlited::get_or_init().run_test(|c: Connection| {
+        let r = block_on(
+            Request::from(Post)
+                .run_async(&c.query().push_sql_str("SELECT 1").push_sql_str("SELECT 2")),
+        );
+
+        assert!(r.is_ok(), "response error: {}", r.err().unwrap());
+
+        let r = response::query::Query::try_from(r.unwrap()).unwrap();
+        assert_eq!(r.results().count(), 2);
+    });
+}
+
+#[test]
+fn request_run_async_test() {
+    TestRqlited::get_or_init().run_test(|c: Connection| {
+        let query = c.query().set_sql_str("SELECT 1");
+        let r = block_on(query.request_run_async());
+
+        assert!(r.is_ok(), "response error: {}", r.err().unwrap());
+
+        let r = response::query::Query::try_from(r.unwrap()).unwrap();
+        assert!(matches!(r.results().next(), Some(Mapping::Standard(_))));
+    });
+}
+
+#[test]
+fn shared_query_async_test() {
+    TestRqlited::get_or_init().run_test(|c: Connection| {
+        let query = Query::from_arc(Arc::new(c), Connection::query).set_sql_str("SELECT 1");
+        let r = block_on(Request::from(Get).run_async(&query));
+
+        assert!(r.is_ok(), "response error: {}", r.err().unwrap());
+    });
+}
+
+#[cfg(feature = "migration")]
+#[test]
+fn migration_async_test() {
+    use rqlite_client::migration::{Downgrade, Migration, SchemaVersion, Upgrade, M};
+    use test_rqlited::lock;
+
+    lock!({
+        TestRqlited::get_or_init().run_test(|c| {
+            let m = Migration::default().push(M(
+                Upgrade::from("CREATE TABLE async_migration_test (id INTEGER)"),
+                Some(Downgrade::from("DROP TABLE async_migration_test")),
+            ));
+
+            let version = block_on(m.migrate_async(&c)).unwrap();
+            assert_eq!(version, m.max());
+
+            let version = block_on(m.rollback_to_async(&c, &SchemaVersion(0))).unwrap();
+            assert_eq!(version, SchemaVersion(0));
+        });
+    });
+}
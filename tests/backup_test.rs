@@ -0,0 +1,43 @@
+#![allow(missing_docs, unused_crate_dependencies)]
+#![cfg(feature = "ureq")]
+
+use rqlite_client::backup::Format;
+use test_rqlited::TestRqlited;
+
+const TEST_TABLE: &str = "backup";
+
+#[test]
+fn backup_test() {
+    TestRqlited::get_or_init().run_test(|c| {
+        let r = c
+            .execute()
+            .push_sql_str(&format!("DROP TABLE IF EXISTS {TEST_TABLE}"))
+            .push_sql_str(&format!(
+                "CREATE TABLE {TEST_TABLE} (id INTEGER NOT NULL PRIMARY KEY, name TEXT)"
+            ))
+            .push_sql_str(&format!("INSERT INTO {TEST_TABLE} (name) VALUES ('fido')"))
+            .request_run();
+
+        assert!(r.is_ok(), "response error: {:?}", r.err().unwrap());
+
+        let mut sql = Vec::new();
+        let r = c
+            .backup()
+            .set_format(Format::Sql)
+            .set_tables(&[TEST_TABLE])
+            .request_backup(&mut sql);
+
+        assert!(r.is_ok(), "response error: {:?}", r.err().unwrap());
+        assert_eq!(r.unwrap(), sql.len() as u64);
+
+        let sql = String::from_utf8(sql).unwrap();
+        assert!(sql.contains(&format!("CREATE TABLE {TEST_TABLE}")));
+        assert!(sql.contains("'fido'"));
+
+        let mut db = Vec::new();
+        let r = c.backup().enable_vacuum().request_backup(&mut db);
+
+        assert!(r.is_ok(), "response error: {:?}", r.err().unwrap());
+        assert!(db.starts_with(b"SQLite format 3\0"));
+    });
+}
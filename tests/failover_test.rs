@@ -0,0 +1,47 @@
+#![allow(missing_docs, unused_crate_dependencies)]
+#![cfg(any(feature = "reqwest", feature = "ureq"))]
+
+use test_rqlited::{TestRqlited, TEST_RQLITED_DB_URL};
+
+use rqlite_client::{response, Connection, Mapping};
+
+const TEST_DEAD_NODE_URL: &str = "http://localhost:1";
+
+#[test]
+fn failover_test() {
+    TestRqlited::get_or_init().run_test(|_c: Connection| {
+        let c = Connection::from_nodes(&[TEST_DEAD_NODE_URL, TEST_RQLITED_DB_URL]);
+        #[cfg(feature = "url")]
+        let c = c.unwrap();
+
+        let query = c.query().set_sql_str("SELECT 1");
+        assert_eq!(query.served_by(), None);
+
+        let r = query.request_run();
+        assert!(r.is_ok(), "response error: {}", r.err().unwrap());
+
+        let r = response::query::Query::try_from(r.unwrap()).unwrap();
+        assert!(matches!(r.results().next(), Some(Mapping::Standard(_))));
+
+        assert_eq!(query.served_by().as_deref(), c.node(1));
+        assert_eq!(c.active_node(), c.node(1).unwrap());
+
+        // the working node is remembered
+        let c2 = c.clone();
+        let query = c2.query().set_sql_str("SELECT 1");
+        assert!(query.to_string().starts_with(c.node(1).unwrap()));
+    });
+}
+
+#[test]
+fn failover_all_dead_test() {
+    let c = Connection::from_nodes(&[TEST_DEAD_NODE_URL, "http://localhost:2"]);
+    #[cfg(feature = "url")]
+    let c = c.unwrap();
+
+    let query = c.query().set_sql_str("SELECT 1");
+    let r = query.request_run();
+
+    assert!(r.is_err());
+    assert_eq!(query.served_by(), None);
+}
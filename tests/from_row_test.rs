@@ -0,0 +1,126 @@
+#![allow(missing_docs, unused_crate_dependencies)]
+#![cfg(feature = "derive")]
+
+use std::collections::HashMap;
+
+use rqlite_client::{
+    response::mapping::{Associative, Standard},
+    DataType, FromRow, Mapping, Value,
+};
+
+#[derive(Debug, FromRow, PartialEq)]
+struct Person {
+    id: i64,
+    #[rqlite(rename = "name")]
+    full_name: String,
+    age: Option<u8>,
+    #[rqlite(default)]
+    nick: String,
+    #[rqlite(default = "default_score")]
+    score: f64,
+    #[rqlite(skip)]
+    is_cached: bool,
+    r#type: String,
+}
+
+fn default_score() -> f64 {
+    1.5
+}
+
+fn standard() -> Standard {
+    Standard::new()
+        .push_column("id")
+        .push_type(DataType::Integer)
+        .push_column("name")
+        .push_type(DataType::Text)
+        .push_column("age")
+        .push_type(DataType::Integer)
+        .push_column("nick")
+        .push_type(DataType::Text)
+        .push_column("type")
+        .push_type(DataType::Text)
+        .push_values(vec![
+            1.into(),
+            "fiona".into(),
+            20.into(),
+            Value::Null,
+            "admin".into(),
+        ])
+        .push_values(vec![
+            2.into(),
+            "declan".into(),
+            Value::Null,
+            "dec".into(),
+            "user".into(),
+        ])
+}
+
+fn expected() -> Vec<Person> {
+    vec![
+        Person {
+            id: 1,
+            full_name: "fiona".to_string(),
+            age: Some(20),
+            nick: String::new(),
+            score: 1.5,
+            is_cached: false,
+            r#type: "admin".to_string(),
+        },
+        Person {
+            id: 2,
+            full_name: "declan".to_string(),
+            age: None,
+            nick: "dec".to_string(),
+            score: 1.5,
+            is_cached: false,
+            r#type: "user".to_string(),
+        },
+    ]
+}
+
+#[test]
+fn from_row_standard_test() {
+    let persons = Mapping::Standard(standard()).rows::<Person>().unwrap();
+    assert_eq!(persons, expected());
+}
+
+#[test]
+fn from_row_associative_test() {
+    let persons = Mapping::Associative(Associative::from(standard()))
+        .rows::<Person>()
+        .unwrap();
+    assert_eq!(persons, expected());
+}
+
+#[test]
+fn from_row_error_test() {
+    let standard = Standard::new()
+        .push_column("id")
+        .push_type(DataType::Text)
+        .push_values(vec!["x".into()]);
+
+    let err = Mapping::Standard(standard).rows::<Person>().unwrap_err();
+    assert_eq!(err.to_string(), "column id: text not compatible with i64");
+
+    let standard = Standard::new()
+        .push_column("id")
+        .push_type(DataType::Integer)
+        .push_values(vec!["x".into()]);
+
+    let err = Mapping::Standard(standard).rows::<Person>().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "column id: can't convert integer value \"x\" to i64"
+    );
+
+    let associative = Associative {
+        rows: vec![HashMap::from([("id".to_string(), Value::from(1))])],
+        time: None,
+        types: HashMap::from([("id".to_string(), DataType::Integer)]),
+    };
+
+    let err = Mapping::Associative(associative)
+        .rows::<Person>()
+        .unwrap_err();
+    assert_eq!(err.to_string(), "no column name");
+}
@@ -0,0 +1,49 @@
+#![allow(missing_docs, unused_crate_dependencies)]
+#![cfg(all(feature = "monitor", any(feature = "reqwest", feature = "ureq")))]
+
+use test_rqlited::{lock, TestRqlited};
+
+use rqlite_client::{response, Connection, Mapping};
+
+#[test]
+fn discover_leader_test() {
+    TestRqlited::get_or_init().run_test(|c: Connection| {
+        assert_eq!(c.leader(), None);
+
+        let leader = c.discover_leader();
+        assert!(leader.is_ok(), "discover error: {}", leader.err().unwrap());
+
+        let leader = leader.unwrap();
+        assert!(leader.is_some());
+        assert_eq!(c.leader(), leader);
+    });
+}
+
+#[test]
+fn leader_pinning_test() {
+    lock!({
+        TestRqlited::get_or_init().run_test(|c: Connection| {
+            let c = c.enable_leader_pinning();
+            assert!(c.is_leader_pinned());
+
+            let query = c
+                .execute()
+                .push_sql_str("CREATE TABLE IF NOT EXISTS leader_pinning (id INTEGER)");
+
+            let r = query.request_run();
+            assert!(r.is_ok(), "response error: {}", r.err().unwrap());
+
+            let r = response::query::Query::try_from(r.unwrap()).unwrap();
+            assert!(matches!(r.results().next(), Some(Mapping::Execute(_))));
+
+            assert!(c.leader().is_some());
+            assert_eq!(query.served_by(), c.leader());
+
+            // reads aren't pinned
+            let query = c.query().set_sql_str("SELECT COUNT(*) FROM leader_pinning");
+            let r = query.request_run();
+            assert!(r.is_ok(), "response error: {}", r.err().unwrap());
+            assert_eq!(query.served_by().as_deref(), c.node(0));
+        });
+    });
+}
@@ -0,0 +1,71 @@
+#![allow(missing_docs, unused_crate_dependencies)]
+#![cfg(feature = "ureq")]
+
+use std::io::Cursor;
+
+use rqlite_client::{backup::Format, Mapping, Value};
+use test_rqlited::TestRqlited;
+
+const TEST_TABLE: &str = "load";
+
+#[test]
+fn load_test() {
+    TestRqlited::get_or_init().run_test(|c| {
+        let r = c
+            .execute()
+            .push_sql_str(&format!("DROP TABLE IF EXISTS {TEST_TABLE}"))
+            .push_sql_str(&format!(
+                "CREATE TABLE {TEST_TABLE} (id INTEGER NOT NULL PRIMARY KEY, name TEXT)"
+            ))
+            .push_sql_str(&format!("INSERT INTO {TEST_TABLE} (name) VALUES ('fido')"))
+            .request_run();
+
+        assert!(r.is_ok(), "response error: {:?}", r.err().unwrap());
+
+        let mut sql = Vec::new();
+        let r = c
+            .backup()
+            .set_format(Format::Sql)
+            .set_tables(&[TEST_TABLE])
+            .request_backup(&mut sql);
+
+        assert!(r.is_ok(), "response error: {:?}", r.err().unwrap());
+
+        let r = c
+            .execute()
+            .push_sql_str(&format!("DROP TABLE {TEST_TABLE}"))
+            .request_run();
+
+        assert!(r.is_ok(), "response error: {:?}", r.err().unwrap());
+
+        let r = c
+            .load()
+            .set_format(Format::Sql)
+            .request_load(Cursor::new(sql));
+
+        assert!(r.is_ok(), "response error: {:?}", r.err().unwrap());
+
+        let r = c
+            .query()
+            .push_sql_str(&format!("SELECT name FROM {TEST_TABLE}"))
+            .request_run()
+            .unwrap()
+            .into_query()
+            .unwrap();
+
+        if let Some(Mapping::Standard(result)) = r.results().next() {
+            assert_eq!(result.value(0, 0), Some(&Value::from("fido")));
+        } else {
+            unreachable!()
+        }
+
+        let r = c
+            .load()
+            .set_format(Format::Sql)
+            .request_load(Cursor::new(format!(
+                "INSERT INTO {TEST_TABLE} (id, name) VALUES (1, 'rex');"
+            )));
+
+        assert!(r.is_err());
+    });
+}
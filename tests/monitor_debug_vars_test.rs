@@ -0,0 +1,20 @@
+#![allow(missing_docs, unused_crate_dependencies)]
+#![cfg(all(feature = "monitor", feature = "ureq"))]
+
+use test_rqlited::TestRqlited;
+
+#[test]
+fn monitor_debug_vars_test() {
+    TestRqlited::get_or_init().run_test(|c| {
+        let q = c.monitor().debug_vars();
+
+        let r = q.request_run_typed();
+
+        assert!(r.is_ok(), "response error: {}", r.err().unwrap());
+        let r = r.unwrap();
+
+        assert!(r.get("cmdline").is_some());
+        assert!(r.http.is_some_and(|http| http.queries.is_some()));
+        assert!(r.store.is_some());
+    });
+}
@@ -9,10 +9,10 @@ fn monitor_nodes_test() {
     TestRqlited::get_or_init().run_test(|c| {
         let q = c.monitor().nodes();
 
-        let r = q.request_run();
+        let r = q.request_run_typed();
 
         assert!(r.is_ok(), "response error: {}", r.err().unwrap());
-        let nodes = response::Nodes::from(r.unwrap());
+        let nodes: response::Nodes = r.unwrap();
         let node = nodes.get("localhost:4002").unwrap();
         assert!(node.leader);
         assert!(node.reachable);
@@ -31,10 +31,10 @@ fn monitor_nodes_ver2_test() {
     TestRqlited::get_or_init().run_test(|c| {
         let q = c.monitor().nodes().enable_version2();
 
-        let r = q.request_run();
+        let r = q.request_run_typed();
 
         assert!(r.is_ok(), "response error: {}", r.err().unwrap());
-        let nodes = response::Nodes::from(r.unwrap());
+        let nodes: response::Nodes = r.unwrap();
         let node = nodes.get("localhost:4002").unwrap();
         assert!(node.leader);
         assert!(node.reachable);
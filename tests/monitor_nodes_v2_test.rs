@@ -12,7 +12,7 @@ fn monitor_nodes_v2_test() {
         let r = q.request_run();
 
         assert!(r.is_ok(), "response error: {}", r.err().unwrap());
-        let nodes = response::NodesV2::from(r.unwrap());
+        let nodes = response::NodesV2::try_from(r.unwrap()).unwrap();
         let node = nodes.first().unwrap();
         assert!(node.leader);
         assert!(node.reachable);
@@ -34,7 +34,7 @@ fn monitor_nodes_v2_ver2_test() {
         let r = q.request_run();
 
         assert!(r.is_ok(), "response error: {}", r.err().unwrap());
-        let nodes = response::NodesV2::from(r.unwrap());
+        let nodes = response::NodesV2::try_from(r.unwrap()).unwrap();
         let node = nodes.first().unwrap();
         assert!(node.leader);
         assert!(node.reachable);
@@ -3,7 +3,6 @@
 
 use std::time::Duration;
 
-use rqlite_client::monitor::response;
 use test_rqlited::TestRqlited;
 
 #[test]
@@ -11,10 +10,10 @@ fn monitor_readyz_test() {
     TestRqlited::get_or_init().run_test(|c| {
         let q = c.monitor().readyz();
 
-        let r = q.request_run();
+        let r = q.request_run_typed();
 
         assert!(r.is_ok(), "response error: {}", r.err().unwrap());
-        let readyz = response::Readyz::from(r.unwrap());
+        let readyz = r.unwrap();
 
         assert!(readyz.is_leader_ok);
         assert!(readyz.is_node_ok);
@@ -32,10 +31,10 @@ fn monitor_readyz_sync_test() {
             .enable_sync()
             .set_timeout(Duration::from_secs(1).into());
 
-        let r = q.request_run();
+        let r = q.request_run_typed();
 
         assert!(r.is_ok(), "response error: {}", r.err().unwrap());
-        let readyz = response::Readyz::from(r.unwrap());
+        let readyz = r.unwrap();
 
         assert!(readyz.is_leader_ok);
         assert!(readyz.is_node_ok);
@@ -1,7 +1,6 @@
 #![allow(missing_docs, unused_crate_dependencies)]
 #![cfg(all(feature = "monitor", feature = "ureq"))]
 
-use rqlite_client::monitor::response;
 use test_rqlited::TestRqlited;
 
 #[test]
@@ -9,14 +8,16 @@ fn monitor_status_test() {
     TestRqlited::get_or_init().run_test(|c| {
         let q = c.monitor();
 
-        let r = q.request_run();
+        let r = q.request_run_typed();
 
         assert!(r.is_ok(), "response error: {}", r.err().unwrap());
-        let r = response::Status::from(r.unwrap());
+        let r = r.unwrap();
 
-        let v = r.0.as_object().unwrap();
-        let cluster = v["cluster"].as_object().unwrap();
+        let cluster = r.get("cluster").unwrap().as_object().unwrap();
 
         assert_eq!(cluster["api_addr"], "localhost:4001");
+        assert!(r.version().is_some());
+        assert!(r.node_id().is_some());
+        assert!(r.raft_state().is_some());
     });
 }
@@ -31,7 +31,7 @@ fn query_associative_get_test() {
 
                 assert!(r.is_ok(), "response error: {}", r.err().unwrap());
 
-                let r = response::query::Query::from(r.unwrap());
+                let r = response::query::Query::try_from(r.unwrap()).unwrap();
                 let result = r.results().next().unwrap();
 
                 match result {
@@ -77,7 +77,7 @@ fn query_associative_post_test() {
 
                 assert!(r.is_ok(), "response error: {}", r.err().unwrap());
 
-                let r = response::query::Query::from(r.unwrap());
+                let r = response::query::Query::try_from(r.unwrap()).unwrap();
                 let result = r.results().next().unwrap();
 
                 match result {
@@ -25,7 +25,7 @@ fn query_associative_test() {
         );
 
         assert!(r.is_ok(), "response error: {:?}", r.err().unwrap());
-        let q = Query::from(r.unwrap());
+        let q = Query::try_from(r.unwrap()).unwrap();
         assert!(q.raft_index().is_some());
     });
 }
@@ -48,7 +48,7 @@ fn query_non_execute_no_raft_index_test() {
         );
 
         assert!(r.is_ok(), "response error: {:?}", r.err().unwrap());
-        let q = Query::from(r.unwrap());
+        let q = Query::try_from(r.unwrap()).unwrap();
         assert!(q.raft_index().is_none());
     });
 }
@@ -71,7 +71,7 @@ fn query_standard_test() {
         );
 
         assert!(r.is_ok(), "response error: {:?}", r.err().unwrap());
-        let q = Query::from(r.unwrap());
+        let q = Query::try_from(r.unwrap()).unwrap();
         assert!(q.raft_index().is_some());
     });
 }
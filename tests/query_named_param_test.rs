@@ -0,0 +1,62 @@
+#![allow(missing_docs, unused_crate_dependencies)]
+#![cfg(feature = "ureq")]
+
+use rqlite_client::{
+    namedparam, request_type::Post, response::Query, DataType, Mapping, Request, RequestBuilder,
+    Value,
+};
+use test_rqlited::TestRqlited;
+
+const TEST_TABLE: &str = "query_named_param";
+
+#[test]
+fn query_named_param_test() {
+    TestRqlited::get_or_init().run_test(|c| {
+        let r = Request::<Post>::new().run(
+            &c.execute()
+                .push_sql_str(&format!("DROP TABLE IF EXISTS {TEST_TABLE}"))
+                .push_sql_str(&format!(
+                    "CREATE TABLE {TEST_TABLE} (id INTEGER NOT NULL PRIMARY KEY, name TEXT, age INTEGER)"
+                )),
+        );
+
+        assert!(r.is_ok(), "response error: {:?}", r.err().unwrap());
+
+        let r = Request::<Post>::new().run(
+            &c.execute()
+                .push_sql_named(
+                    &format!("INSERT INTO {TEST_TABLE} (name, age) VALUES (:name, :age)"),
+                    namedparam! { name => "fido", age => 3 },
+                )
+                .push_sql_named(
+                    &format!("INSERT INTO {TEST_TABLE} (name, age) VALUES (:name, :age)"),
+                    namedparam! { age => 5, name => "rex" },
+                ),
+        );
+
+        assert!(r.is_ok(), "response error: {:?}", r.err().unwrap());
+
+        for (name, age) in [("rex", 5), ("fido", 3)] {
+            let r = c
+                .query()
+                .set_sql_named(
+                    &format!("SELECT name, age FROM {TEST_TABLE} WHERE name = :name"),
+                    namedparam! { name => name },
+                )
+                .request_run();
+
+            assert!(r.is_ok(), "response error: {:?}", r.err().unwrap());
+            let r = r.unwrap();
+
+            if let Some(Mapping::Standard(result)) = Query::try_from(r).unwrap().results().next() {
+                assert_eq!(result.types[1], DataType::Integer);
+                let values = result.values.as_ref().unwrap();
+                assert_eq!(values.len(), 1);
+                assert_eq!(values[0][0], Value::from(name));
+                assert_eq!(values[0][1], Value::from(age));
+            } else {
+                unreachable!()
+            }
+        }
+    });
+}
@@ -0,0 +1,60 @@
+#![allow(missing_docs, unused_crate_dependencies)]
+#![cfg(feature = "ureq")]
+
+use rqlite_client::{request_type::Post, DataType, Request, RequestBuilder, Value};
+use test_rqlited::TestRqlited;
+
+const TEST_TABLE: &str = "query_stream";
+
+#[test]
+fn query_stream_test() {
+    TestRqlited::get_or_init().run_test(|c| {
+        let r = Request::<Post>::new().run(
+            &c.execute()
+                .push_sql_str(&format!("DROP TABLE IF EXISTS {TEST_TABLE}"))
+                .push_sql_str(&format!(
+                    "CREATE TABLE {TEST_TABLE} (id INTEGER NOT NULL PRIMARY KEY, name TEXT)"
+                )),
+        );
+
+        assert!(r.is_ok(), "response error: {:?}", r.err().unwrap());
+
+        let r = Request::<Post>::new().run(&c.execute().push_sql_str(&format!(
+            "INSERT INTO {TEST_TABLE} (id, name) \
+                WITH RECURSIVE seq(i) AS (SELECT 0 UNION ALL SELECT i + 1 FROM seq WHERE i < 99) \
+                SELECT i, 'name ' || i FROM seq"
+        )));
+
+        assert!(r.is_ok(), "response error: {:?}", r.err().unwrap());
+
+        let r = c
+            .query()
+            .set_sql_str(&format!("SELECT id, name FROM {TEST_TABLE} ORDER BY id"))
+            .request_stream();
+
+        assert!(r.is_ok(), "response error: {:?}", r.err().unwrap());
+        let mut stream = r.unwrap();
+
+        let mut count = 0;
+        while let Some(values) = stream.next() {
+            let values = values.unwrap();
+            assert_eq!(stream.columns(), ["id", "name"]);
+            assert_eq!(stream.types(), [DataType::Integer, DataType::Text]);
+            assert_eq!(values[0], Value::from(count));
+            assert_eq!(
+                stream.row(&values).get::<String>("name"),
+                format!("name {count}")
+            );
+            count += 1;
+        }
+        assert_eq!(count, 100);
+
+        let r = c
+            .query()
+            .set_sql_str(&format!("SELECT * FROM {TEST_TABLE}"))
+            .set_associative()
+            .request_stream();
+
+        assert!(r.is_err());
+    });
+}
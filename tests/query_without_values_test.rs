@@ -26,7 +26,7 @@ fn query_standard_without_values_test() {
         assert!(r.is_ok(), "response error: {:?}", r.err().unwrap());
         let r = r.unwrap();
 
-        if let Some(Mapping::Standard(result)) = Query::from(r).results().next() {
+        if let Some(Mapping::Standard(result)) = Query::try_from(r).unwrap().results().next() {
             assert_eq!(result.columns[0], "name");
             assert_eq!(result.types[0], DataType::Text);
         } else {
@@ -51,7 +51,7 @@ fn query_associative_without_values_test() {
         assert!(r.is_ok(), "response error: {:?}", r.err().unwrap());
         let r = r.unwrap();
 
-        if let Some(Mapping::Associative(result)) = Query::from(r).results().next() {
+        if let Some(Mapping::Associative(result)) = Query::try_from(r).unwrap().results().next() {
             assert!(result.rows.is_empty());
             assert_eq!(result.types["name"], DataType::Text);
         } else {
@@ -0,0 +1,18 @@
+#![allow(missing_docs, unused_crate_dependencies)]
+#![cfg(all(feature = "monitor", feature = "ureq"))]
+
+use rqlite_client::ErrorKind;
+use test_rqlited::TestRqlited;
+
+#[test]
+fn remove_not_found_test() {
+    TestRqlited::get_or_init().run_test(|c| {
+        let r = c.remove_node("no_such_node").request_remove_verified();
+
+        assert!(r.is_err());
+        assert_eq!(
+            r.unwrap_err().kind(),
+            Some(ErrorKind::NodeNotFound("no_such_node".to_string()))
+        );
+    });
+}
@@ -14,7 +14,7 @@ fn level_auto_test() {
 
         assert!(r.is_ok(), "response error: {}", r.err().unwrap());
 
-        let r = response::query::Query::from(r.unwrap());
+        let r = response::query::Query::try_from(r.unwrap()).unwrap();
         let result = r.results().next().unwrap();
 
         match result {
@@ -45,7 +45,7 @@ fn level_linearizable_test() {
 
         assert!(r.is_ok(), "response error: {}", r.err().unwrap());
 
-        let r = response::query::Query::from(r.unwrap());
+        let r = response::query::Query::try_from(r.unwrap()).unwrap();
         let result = r.results().next().unwrap();
 
         match result {
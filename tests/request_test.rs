@@ -55,7 +55,7 @@ fn nolevel_test() {
 
         assert!(r.is_ok(), "response error: {}", r.err().unwrap());
 
-        let r = response::query::Query::from(r.unwrap());
+        let r = response::query::Query::try_from(r.unwrap()).unwrap();
         let result = r.results().next().unwrap();
 
         match result {
@@ -82,7 +82,7 @@ fn nolevel_request_run_test() {
 
         assert!(r.is_ok(), "response error: {}", r.err().unwrap());
 
-        let r = response::query::Query::from(r.unwrap());
+        let r = response::query::Query::try_from(r.unwrap()).unwrap();
         let result = r.results().next().unwrap();
 
         match result {
@@ -132,7 +132,7 @@ fn query_post_switch_test() {
 
         assert!(r.is_ok(), "response error: {}", r.err().unwrap());
 
-        let r = response::query::Query::from(r.unwrap());
+        let r = response::query::Query::try_from(r.unwrap()).unwrap();
         let result = r.results().next().unwrap();
 
         match result {
@@ -202,7 +202,7 @@ fn weak_multi_test() {
 
         assert!(r.is_ok(), "response error: {}", r.err().unwrap());
 
-        let r = response::query::Query::from(r.unwrap());
+        let r = response::query::Query::try_from(r.unwrap()).unwrap();
 
         let mut results = r.results();
         let result = results.next().unwrap();
@@ -0,0 +1,99 @@
+#![allow(missing_docs, unused_crate_dependencies)]
+#![cfg(feature = "reqwest")]
+
+use test_rqlited::TestRqlited;
+
+use rqlite_client::{
+    request_type::{Get, Post},
+    response::{
+        self,
+        mapping::{self, Mapping},
+    },
+    AsyncRequestBuilder, Connection, DataType, RequestBuilder, ReqwestRequest,
+};
+
+fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap()
+        .block_on(future)
+}
+
+#[test]
+fn reqwest_nolevel_test() {
+    TestRqlited::get_or_init().run_test(|c: Connection| {
+        let r = ReqwestRequest::from(Get).run(&c.query().set_sql_str("SELECT 1"));
+
+        assert!(r.is_ok(), "response error: {}", r.err().unwrap());
+
+        let r = response::query::Query::try_from(r.unwrap()).unwrap();
+        let result = r.results().next().unwrap();
+
+        match result {
+            Mapping::Standard(result) => {
+                assert_eq!(
+                    result,
+                    &mapping::Standard {
+                        columns: vec!["1".to_string()],
+                        time: None,
+                        types: vec![DataType::Integer],
+                        values: Some(vec![vec![1.into()]])
+                    }
+                );
+            }
+            _ => unreachable!(),
+        }
+    });
+}
+
+#[test]
+fn reqwest_nolevel_multi_test() {
+    TestRqlited::get_or_init().run_test(|c: Connection| {
+        let r = ReqwestRequest::from(Post)
+            .run(&c.query().push_sql_str("SELECT 1").push_sql_str("SELECT 2"));
+
+        assert!(r.is_ok(), "response error: {}", r.err().unwrap());
+
+        let r = response::query::Query::try_from(r.unwrap()).unwrap();
+        assert_eq!(r.results().count(), 2);
+    });
+}
+
+#[test]
+fn reqwest_nolevel_async_test() {
+    TestRqlited::get_or_init().run_test(|c: Connection| {
+        let r = block_on(ReqwestRequest::from(Get).run_async(&c.query().set_sql_str("SELECT 1")));
+
+        assert!(r.is_ok(), "response error: {}", r.err().unwrap());
+
+        let r = response::query::Query::try_from(r.unwrap()).unwrap();
+        assert!(matches!(r.results().next(), Some(Mapping::Standard(_))));
+    });
+}
+
+#[test]
+fn reqwest_nolevel_multi_async_test() {
+    TestRqlited::get_or_init().run_test(|c: Connection| {
+        let r = block_on(
+            ReqwestRequest::from(Post)
+                .run_async(&c.query().push_sql_str("SELECT 1").push_sql_str("SELECT 2")),
+        );
+
+        assert!(r.is_ok(), "response error: {}", r.err().unwrap());
+
+        let r = response::query::Query::try_from(r.unwrap()).unwrap();
+        assert_eq!(r.results().count(), 2);
+    });
+}
+
+#[cfg(feature = "monitor")]
+#[test]
+fn reqwest_monitor_readyz_test() {
+    TestRqlited::get_or_init().run_test(|c: Connection| {
+        let r = ReqwestRequest::from(Get).run(&c.monitor().readyz());
+
+        assert!(r.is_ok(), "response error: {}", r.err().unwrap());
+        assert!(matches!(r.unwrap(), rqlite_client::Response::Readyz(_)));
+    });
+}
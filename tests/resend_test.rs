@@ -0,0 +1,136 @@
+#![allow(missing_docs, unused_crate_dependencies)]
+#![cfg(any(feature = "reqwest", feature = "ureq"))]
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+use std::time::Duration;
+
+use rqlite_client::{Connection, Error, RetryPolicy};
+
+/// Node answering every request with `status` and counting the requests
+struct StubNode {
+    requests: Arc<AtomicUsize>,
+    url: String,
+}
+
+impl StubNode {
+    fn start(status: &'static str) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&requests);
+
+        let _ = std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else {
+                    continue;
+                };
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut content_length = 0;
+
+                loop {
+                    let mut line = String::new();
+                    if reader.read_line(&mut line).unwrap_or_default() == 0 || line == "\r\n" {
+                        break;
+                    }
+                    if let Some((name, value)) = line.split_once(':') {
+                        if name.eq_ignore_ascii_case("content-length") {
+                            content_length = value.trim().parse().unwrap_or_default();
+                        }
+                    }
+                }
+
+                let mut body = vec![0; content_length];
+                let _ = reader.read_exact(&mut body);
+                let _ = counter.fetch_add(1, Ordering::SeqCst);
+
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 {status}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                );
+            }
+        });
+
+        Self { requests, url }
+    }
+
+    fn requests(&self) -> usize {
+        self.requests.load(Ordering::SeqCst)
+    }
+}
+
+#[test]
+fn write_no_failover_test() {
+    let node1 = StubNode::start("503 Service Unavailable");
+    let node2 = StubNode::start("503 Service Unavailable");
+
+    let c = Connection::from_nodes(&[&node1.url, &node2.url]);
+    #[cfg(feature = "url")]
+    let c = c.unwrap();
+
+    let r = c
+        .execute()
+        .push_sql_str("INSERT INTO tbl (name) VALUES ('fido')")
+        .request_run();
+
+    assert!(matches!(r, Err(Error::HttpError(503, _))), "{r:?}");
+    assert_eq!(node1.requests() + node2.requests(), 1);
+
+    // reads fail over
+    let r = c.query().set_sql_str("SELECT 1").request_run();
+
+    assert!(matches!(r, Err(Error::HttpError(503, _))), "{r:?}");
+    assert_eq!(node1.requests() + node2.requests(), 3);
+}
+
+#[test]
+fn write_idempotent_failover_test() {
+    let node1 = StubNode::start("500 Internal Server Error");
+    let node2 = StubNode::start("500 Internal Server Error");
+
+    let c = Connection::from_nodes(&[&node1.url, &node2.url]);
+    #[cfg(feature = "url")]
+    let c = c.unwrap();
+
+    let r = c
+        .execute()
+        .push_sql_str("DELETE FROM tbl WHERE id = 1")
+        .set_idempotent()
+        .request_run();
+
+    assert!(matches!(r, Err(Error::HttpError(500, _))), "{r:?}");
+    assert_eq!(node1.requests(), 1);
+    assert_eq!(node2.requests(), 1);
+}
+
+#[test]
+fn write_retry_policy_test() {
+    let node1 = StubNode::start("503 Service Unavailable");
+    let node2 = StubNode::start("503 Service Unavailable");
+
+    let c = Connection::from_nodes(&[&node1.url, &node2.url]);
+    #[cfg(feature = "url")]
+    let c = c.unwrap();
+    let c = c.set_retry_policy(RetryPolicy::new().set_backoff(Duration::from_millis(1)));
+
+    let r = c
+        .execute()
+        .push_sql_str("INSERT INTO tbl (name) VALUES ('fido')")
+        .request_run();
+
+    assert!(matches!(r, Err(Error::HttpError(503, _))), "{r:?}");
+    assert_eq!(node1.requests() + node2.requests(), 1);
+
+    // queued writes are safe to be sent again
+    let r = c
+        .execute_queue()
+        .push_sql_str("INSERT INTO tbl (name) VALUES ('fido')")
+        .request_run();
+
+    assert!(matches!(r, Err(Error::HttpError(503, _))), "{r:?}");
+    assert_eq!(node1.requests() + node2.requests(), 7);
+}
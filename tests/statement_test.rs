@@ -0,0 +1,64 @@
+#![allow(missing_docs, unused_crate_dependencies)]
+#![cfg(feature = "ureq")]
+
+use rqlite_client::{namedparam, varparam, Mapping, Value};
+use test_rqlited::TestRqlited;
+
+const TEST_TABLE: &str = "statement";
+
+#[test]
+fn statement_test() {
+    TestRqlited::get_or_init().run_test(|c| {
+        let r = c
+            .execute()
+            .push_sql_str(&format!("DROP TABLE IF EXISTS {TEST_TABLE}"))
+            .push_sql_str(&format!(
+                "CREATE TABLE {TEST_TABLE} (id INTEGER NOT NULL PRIMARY KEY, name TEXT, age INTEGER)"
+            ))
+            .request_run();
+
+        assert!(r.is_ok(), "response error: {:?}", r.err().unwrap());
+
+        let mut statement = c
+            .execute()
+            .enable_transaction()
+            .prepare(&format!(
+                "INSERT INTO {TEST_TABLE} (name, age) VALUES (?, ?)"
+            ))
+            .unwrap();
+
+        let r = statement.execute(&varparam!["fido", 3]);
+        assert!(r.is_ok(), "response error: {:?}", r.err().unwrap());
+
+        let r = statement.execute_many(&[varparam!["rex", 5], varparam!["bello", 7]]);
+        assert!(r.is_ok(), "response error: {:?}", r.err().unwrap());
+        let r = r.unwrap().into_query().unwrap();
+        assert_eq!(r.results().count(), 2);
+
+        assert!(statement.execute(&varparam!["rex"]).is_err());
+
+        let mut statement = c
+            .query()
+            .prepare(&format!(
+                "SELECT name, age FROM {TEST_TABLE} WHERE name = :name"
+            ))
+            .unwrap();
+
+        for (name, age) in [("fido", 3), ("bello", 7)] {
+            let r = statement.execute_named(namedparam! { name => name });
+            assert!(r.is_ok(), "response error: {:?}", r.err().unwrap());
+
+            if let Some(Mapping::Standard(result)) =
+                r.unwrap().into_query().unwrap().results().next()
+            {
+                assert_eq!(result.value(0, 1), Some(&Value::from(age)));
+            } else {
+                unreachable!()
+            }
+        }
+
+        assert!(statement
+            .execute_named(namedparam! { age => 3 })
+            .is_err());
+    });
+}